@@ -1,6 +1,12 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::helpers::deserialize_list;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CompetitionSeasonsApiResponse<Season> {
+    #[serde(
+        deserialize_with = "deserialize_list",
+        bound(deserialize = "Season: DeserializeOwned")
+    )]
     pub seasons: Vec<Season>,
 }
@@ -1,6 +1,12 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::helpers::deserialize_list;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CompetitionsApiResponse<Competition> {
+    #[serde(
+        deserialize_with = "deserialize_list",
+        bound(deserialize = "Competition: DeserializeOwned")
+    )]
     pub competitions: Vec<Competition>,
 }
@@ -1,13 +1,22 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::helpers::deserialize_list;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CompetitorStatisticsApiResponse<Player> {
+    #[serde(bound(deserialize = "Player: DeserializeOwned"))]
     pub competitor: Competitor<Player>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Competitor<Player> {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
+    #[serde(
+        deserialize_with = "deserialize_list",
+        bound(deserialize = "Player: DeserializeOwned")
+    )]
     pub players: Vec<Player>,
 }
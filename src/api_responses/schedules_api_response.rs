@@ -1,11 +1,19 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{helpers::deserialize_list, models::SportEventStatus};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SchedulesApiResponse<Event> {
+    #[serde(
+        deserialize_with = "deserialize_list",
+        bound(deserialize = "Event: DeserializeOwned")
+    )]
     pub schedules: Vec<Schedule<Event>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Schedule<Event> {
     pub sport_event: Event,
+    #[serde(default)]
+    pub sport_event_status: Option<SportEventStatus>,
 }
@@ -0,0 +1,15 @@
+mod quota_middleware;
+mod rate_limited_client;
+mod recording_client;
+mod replay_client;
+mod retry_counting_middleware;
+mod sportradar_client;
+mod sportradar_client_builder;
+
+pub use quota_middleware::QuotaMiddleware;
+pub use rate_limited_client::RateLimitedClient;
+pub use recording_client::RecordingClient;
+pub use replay_client::ReplayClient;
+pub use retry_counting_middleware::RetryCountingMiddleware;
+pub use sportradar_client::SportradarClient;
+pub use sportradar_client_builder::SportradarClientBuilder;
@@ -0,0 +1,23 @@
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+
+use crate::helpers::record_api_request;
+
+/// Counts every sent request in the API usage of its key (see [`record_api_request`]). Must be
+/// added after the retry middleware, so that the retries, which use up the quota too, are counted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuotaMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for QuotaMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        record_api_request(req.url().as_str());
+        next.run(req, extensions).await
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+use crate::services::ApiService;
+
+/// An HTTP client which sends the requests of the wrapped client no faster than the rate limit of
+/// the API key (see [`crate::ApiConfig::rate_limit`]), even when the fetch sends them
+/// concurrently. The retries of the wrapped client are not delayed.
+///
+/// The clones share the limit, so the concurrent fetches of the clones are limited together.
+#[derive(Debug, Clone)]
+pub struct RateLimitedClient<HttpClient: ApiService> {
+    http_client: HttpClient,
+    interval: Duration,
+    next_slot: Arc<Mutex<Instant>>,
+}
+
+impl<HttpClient: ApiService> RateLimitedClient<HttpClient> {
+    /// Wraps the HTTP client, sending at most `rate_limit` requests per second.
+    pub fn new(http_client: HttpClient, rate_limit: u32) -> Self {
+        Self {
+            http_client,
+            interval: Duration::from_secs(1) / rate_limit.max(1),
+            next_slot: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+}
+
+impl<HttpClient: ApiService + Sync> ApiService for RateLimitedClient<HttpClient> {
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>> {
+        // Take the next free slot, so the concurrent requests wait in turns
+        let slot = {
+            let mut next_slot = self.next_slot.lock().expect("Rate limit lock is poisoned");
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+
+        self.http_client.get_text(url).await
+    }
+}
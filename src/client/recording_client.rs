@@ -0,0 +1,39 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use crate::{helpers::fixture_path, services::ApiService};
+
+/// An HTTP client which fetches the responses with the wrapped client and saves them into a
+/// fixture directory, to be served back later by the [`ReplayClient`](super::ReplayClient).
+///
+/// The fixture files are named after the request URLs without the API key (see
+/// [`fixture_path`]), so the recorded fixtures can be committed safely.
+#[derive(Debug, Clone)]
+pub struct RecordingClient<HttpClient: ApiService> {
+    http_client: HttpClient,
+    fixture_dir: PathBuf,
+}
+
+impl<HttpClient: ApiService> RecordingClient<HttpClient> {
+    /// Wraps the HTTP client, saving the responses into the fixture directory.
+    pub fn new(http_client: HttpClient, fixture_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            http_client,
+            fixture_dir: fixture_dir.into(),
+        }
+    }
+}
+
+impl<HttpClient: ApiService + Sync> ApiService for RecordingClient<HttpClient> {
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>> {
+        let url = url.into();
+        let body = self.http_client.get_text(url.clone()).await?;
+
+        let path = fixture_path(&self.fixture_dir, &url);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &body)?;
+
+        Ok(body)
+    }
+}
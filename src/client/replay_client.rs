@@ -0,0 +1,44 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use crate::{
+    helpers::{fixture_path, scrub_api_key},
+    services::ApiService,
+};
+
+/// An HTTP client which serves the responses saved by the
+/// [`RecordingClient`](super::RecordingClient) from a fixture directory instead of calling the
+/// API, so the whole fetch pipeline can run offline and deterministically.
+#[derive(Debug, Clone)]
+pub struct ReplayClient {
+    fixture_dir: PathBuf,
+}
+
+impl ReplayClient {
+    /// Creates a client serving the responses from the fixture directory.
+    pub fn new(fixture_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            fixture_dir: fixture_dir.into(),
+        }
+    }
+}
+
+impl ApiService for ReplayClient {
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>> {
+        let url = url.into();
+        let path = fixture_path(&self.fixture_dir, &url);
+
+        // The fixtures are the cache of the responses
+        let body = fs::read_to_string(&path);
+        tracing::Span::current().record("cache", if body.is_ok() { "hit" } else { "miss" });
+
+        body.map_err(|err| {
+            format!(
+                "No recorded response for {} ({}): {}",
+                scrub_api_key(&url),
+                path.display(),
+                err
+            )
+            .into()
+        })
+    }
+}
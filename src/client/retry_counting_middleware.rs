@@ -0,0 +1,39 @@
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+
+use crate::helpers::report_retry;
+
+// The number of the attempts of the request so far. The retry middleware passes the same
+// extensions to every attempt.
+#[derive(Debug, Clone, Copy)]
+struct Attempts(u32);
+
+/// Reports every repeated attempt of a request as a retry (see [`report_retry`]) and records the
+/// number of retries in the `api_request` span. Must be added after the retry middleware, so that
+/// it sees every attempt.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetryCountingMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RetryCountingMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let attempts = extensions
+            .get::<Attempts>()
+            .map_or(0, |attempts| attempts.0)
+            + 1;
+        extensions.insert(Attempts(attempts));
+        if attempts > 1 {
+            tracing::Span::current().record("retries", attempts - 1);
+            tracing::debug!(attempt = attempts, "Retrying the request");
+            report_retry();
+        }
+
+        next.run(req, extensions).await
+    }
+}
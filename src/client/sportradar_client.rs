@@ -0,0 +1,152 @@
+use std::error::Error;
+
+use crate::{
+    config::ApiConfig,
+    helpers::{check_quota_budget, flush_quota_usage, scrub_api_key},
+    models::{AppState, Competition, CompetitionSeason, Player, SeasonData, Sport},
+    services::{ApiService, AppStateService, SportApiService},
+};
+
+use super::SportradarClientBuilder;
+
+/// A configured Sportradar API client with one-call functions for the data used by the CLI.
+///
+/// The functions fetch the data of the first sport API in the config, use
+/// [`SportradarClient::for_sport`] to switch to another sport.
+///
+/// Every function runs the same fetch pipeline as the CLI (see [`AppStateService`]) on a fresh
+/// [`AppState`], so the calls are independent of each other. Use [`SportradarClient::app_state`]
+/// to drive the pipeline step by step instead.
+#[derive(Debug, Clone)]
+pub struct SportradarClient<HttpClient: ApiService> {
+    http_client: HttpClient,
+    config: ApiConfig,
+}
+
+impl SportradarClient<reqwest_middleware::ClientWithMiddleware> {
+    /// Returns a builder of a client retrying the transient errors.
+    pub fn builder() -> SportradarClientBuilder {
+        SportradarClientBuilder::new()
+    }
+}
+
+impl<HttpClient: ApiService + Clone> SportradarClient<HttpClient> {
+    /// Creates a client on top of any HTTP client implementing [`ApiService`].
+    pub fn new(http_client: HttpClient, config: ApiConfig) -> Self {
+        Self {
+            http_client,
+            config,
+        }
+    }
+
+    /// Returns the HTTP client.
+    pub fn http_client(&self) -> &HttpClient {
+        &self.http_client
+    }
+
+    /// Returns the API settings.
+    pub fn config(&self) -> &ApiConfig {
+        &self.config
+    }
+
+    /// Returns a copy of the client using only the sport API with the given name in the URL (e.g.
+    /// `tennis`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sport API is not in the config.
+    pub fn for_sport(&self, api: &str) -> Result<Self, String> {
+        let sport_api = self.config.find_sport(Some(api))?.clone();
+        let config = ApiConfig {
+            sports: vec![sport_api],
+            ..self.config.clone()
+        };
+
+        Ok(Self::new(self.http_client.clone(), config))
+    }
+
+    /// Returns the sport whose data the client fetches (the first sport API in the config).
+    pub fn sport(&self) -> Result<Sport, String> {
+        Ok(Sport::from_sport_api(self.config.find_sport(None)?))
+    }
+
+    /// Creates a new, empty application state using the client's HTTP client and settings.
+    pub fn app_state(&self) -> AppState<HttpClient> {
+        AppState::new(self.http_client.clone(), self.config.clone())
+    }
+
+    // Get a new application state with the client's sport selected
+    fn app_state_with_sport(&self) -> Result<AppState<HttpClient>, Box<dyn Error>> {
+        let mut app = self.app_state();
+        app.set_selected_sport(&self.sport()?);
+
+        Ok(app)
+    }
+
+    /// Fetches the raw response body of an endpoint path of the sport (see
+    /// [`ApiConfig::raw_api_url`]), e.g. `competitors/sr:competitor:17/profile`, with the client's
+    /// API key, retries, and API usage accounting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request would go over the monthly budget (see
+    /// [`ApiConfig::monthly_budget`]) or the request fails.
+    pub async fn fetch_raw(&self, path: &str) -> Result<String, Box<dyn Error>> {
+        check_quota_budget(&self.config, 1)?;
+        let url = self.config.raw_api_url(&self.sport()?, path);
+        tracing::debug!(endpoint = %scrub_api_key(&url), "Fetching the raw response");
+
+        let response = self.http_client.get_text(url).await;
+        flush_quota_usage(&self.config.quota_file);
+        response
+    }
+
+    /// Fetches the competitions allowed by the settings.
+    pub async fn fetch_competitions(&self) -> Result<Vec<Competition>, Box<dyn Error>> {
+        let mut app = self.app_state_with_sport()?;
+        app.on_sport_select().await?;
+
+        Ok(app.competitions().iter().cloned().collect())
+    }
+
+    /// Fetches the enabled seasons of the competition, the most recent first.
+    pub async fn fetch_seasons(
+        &self,
+        competition: &Competition,
+    ) -> Result<Vec<CompetitionSeason>, Box<dyn Error>> {
+        let mut app = self.app_state_with_sport()?;
+        app.set_selected_competition(competition);
+        app.on_competition_select().await?;
+
+        Ok(app.seasons().to_vec())
+    }
+
+    /// Fetches all the data of the season: the sport events, the competitors, and the players
+    /// with their season statistics.
+    pub async fn fetch_season(
+        &self,
+        competition: &Competition,
+        season: &CompetitionSeason,
+    ) -> Result<SeasonData, Box<dyn Error>> {
+        let mut app = self.app_state_with_sport()?;
+        app.set_selected_competition(competition);
+        app.set_selected_season(season.clone());
+        app.on_season_select().await?;
+
+        Ok(app
+            .season_snapshot()
+            .expect("The competition and the season are selected"))
+    }
+
+    /// Fetches the players of the season with their season statistics.
+    pub async fn fetch_season_players(
+        &self,
+        season: &CompetitionSeason,
+    ) -> Result<Vec<Player>, Box<dyn Error>> {
+        let mut app = self.app_state_with_sport()?;
+        app.set_selected_season(season.clone());
+        app.on_season_select().await?;
+
+        Ok(app.players().values().cloned().collect())
+    }
+}
@@ -0,0 +1,109 @@
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
+
+use crate::config::{ApiConfig, SportApi};
+
+use super::{QuotaMiddleware, RetryCountingMiddleware, SportradarClient};
+
+/// Builds a [`SportradarClient`] with the HTTP client retrying the transient errors.
+///
+/// Start with [`SportradarClientBuilder::new`] (the default settings) or
+/// [`SportradarClientBuilder::from_env`] (the settings from the environment variables) and
+/// override the settings you need.
+#[derive(Debug, Clone)]
+pub struct SportradarClientBuilder {
+    config: ApiConfig,
+    max_retries: u32,
+}
+
+impl Default for SportradarClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SportradarClientBuilder {
+    /// Creates a builder with the default settings (see [`ApiConfig::default`]) and 3 retries.
+    pub fn new() -> Self {
+        Self {
+            config: ApiConfig::default(),
+            max_retries: 3,
+        }
+    }
+
+    /// Creates a builder with the settings read from the environment variables (see
+    /// [`ApiConfig::from_env`]).
+    pub fn from_env() -> Result<Self, String> {
+        Ok(Self::new().config(ApiConfig::from_env()?))
+    }
+
+    /// Replaces all the API settings.
+    pub fn config(mut self, config: ApiConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the API key.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.config.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the base URL of the API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the access level of the API key (`trial` or `production`).
+    pub fn access_level(mut self, access_level: impl Into<String>) -> Self {
+        self.config.access_level = access_level.into();
+        self
+    }
+
+    /// Sets the language code of the response texts.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.config.language_code = language_code.into();
+        self
+    }
+
+    /// Sets the sport APIs reachable with the API key (only the Soccer API by default).
+    pub fn sports(mut self, sports: Vec<SportApi>) -> Self {
+        self.config.sports = sports;
+        self
+    }
+
+    /// Sets how many times a request failed with a transient error is retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the number of the API requests allowed per calendar month (UTC). The fetches which
+    /// would go over it are refused.
+    pub fn monthly_budget(mut self, monthly_budget: u64) -> Self {
+        self.config.monthly_budget = Some(monthly_budget);
+        self
+    }
+
+    /// Sets the number of the requests per second allowed for the API key (1 by default).
+    pub fn rate_limit(mut self, rate_limit: u32) -> Self {
+        self.config.rate_limit = rate_limit;
+        self
+    }
+
+    /// Builds the client. The requests failed with a transient error are retried with exponentially
+    /// increasing intervals between the attempts, and the retries are reported to the progress
+    /// observer of the fetch (see [`crate::services::FetchProgressService`]). Every sent request is
+    /// counted in the API usage (see [`crate::helpers::quota_usage`]).
+    pub fn build(self) -> SportradarClient<ClientWithMiddleware> {
+        let retry_policy = ExponentialBackoff::builder().build_with_max_retries(self.max_retries);
+        let http_client = ClientBuilder::new(reqwest::Client::new())
+            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
+            .with(RetryCountingMiddleware)
+            .with(QuotaMiddleware)
+            .build();
+
+        SportradarClient::new(http_client, self.config)
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+use clap::Args;
+use colorize::AnsiColor;
+
+use sportradar::{
+    client::RateLimitedClient,
+    helpers::load_season_snapshot,
+    models::{FetchProgress, SeasonData},
+    services::{ApiService, AppStateService, FetchProgressService},
+    SportradarClient,
+};
+
+use crate::interactive::ProgressBar;
+
+#[derive(Debug, Args)]
+pub struct ArchiveArgs {
+    /// Id of the competition to archive, e.g. `sr:competition:17`
+    pub competition: String,
+
+    /// Fetch again the seasons which are already in the local cache
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+/// Shows the progress of the season fetch and keeps its last state, to tell whether all the
+/// competitors were fetched.
+#[derive(Debug, Default)]
+struct SeasonProgress {
+    progress_bar: Option<ProgressBar>,
+    last: Mutex<FetchProgress>,
+}
+
+impl FetchProgressService for SeasonProgress {
+    fn on_start(&self, progress: &FetchProgress) {
+        self.on_progress(progress);
+    }
+
+    fn on_progress(&self, progress: &FetchProgress) {
+        *self.last.lock().expect("Progress lock is poisoned") = *progress;
+        if let Some(progress_bar) = &self.progress_bar {
+            progress_bar.on_progress(progress);
+        }
+    }
+
+    fn on_finish(&self, progress: &FetchProgress) {
+        if let Some(progress_bar) = &self.progress_bar {
+            progress_bar.on_finish(progress);
+        }
+    }
+}
+
+/// Fetches every enabled season of the competition, the most recent first, into the local cache,
+/// so that the seasons can be queried offline later (e.g. with the `leaders` and `search`
+/// commands).
+///
+/// The requests are sent no faster than the rate limit of the API key. The seasons already in the
+/// cache are skipped, so an interrupted archive resumes where it stopped. A season whose
+/// competitors were not all fetched is removed from the cache, to be fetched again by the next
+/// run.
+pub async fn archive<HttpClient: ApiService + Clone + Sync>(
+    client: &SportradarClient<HttpClient>,
+    args: &ArchiveArgs,
+) -> Result<(), Box<dyn Error>> {
+    let config = client.config().clone();
+    let client = SportradarClient::new(
+        RateLimitedClient::new(client.http_client().clone(), config.rate_limit),
+        config,
+    );
+
+    let competition = client
+        .fetch_competitions()
+        .await?
+        .into_iter()
+        .find(|competition| competition.id == args.competition)
+        .ok_or_else(|| {
+            format!(
+                "Competition {} is not among the allowed competitions of the sport",
+                args.competition
+            )
+        })?;
+    let seasons = client.fetch_seasons(&competition).await?;
+    println!(
+        "{}",
+        format!("Archiving {} seasons of {}", seasons.len(), competition)
+            .green()
+            .bold()
+    );
+
+    let mut failed_seasons = 0;
+    for (index, season) in seasons.iter().enumerate() {
+        let prefix = format!("[{}/{}] {}", index + 1, seasons.len(), season);
+        if !args.refresh
+            && load_season_snapshot::<SeasonData>(&client.config().cache_dir, &season.id).is_some()
+        {
+            println!("{}: already archived", prefix);
+            continue;
+        }
+
+        let progress = Arc::new(SeasonProgress {
+            progress_bar: ProgressBar::for_stderr(),
+            ..SeasonProgress::default()
+        });
+        let mut app = client.app_state();
+        app.set_selected_sport(&client.sport()?);
+        app.set_selected_competition(&competition);
+        app.set_selected_season(season.clone());
+        app.set_progress_observer(progress.clone());
+
+        let result = app.on_season_select().await;
+        let last = *progress.last.lock().expect("Progress lock is poisoned");
+        match result {
+            Ok(()) if last.failed == 0 => {
+                app.save_season_snapshot()?;
+                println!("{}: {} players archived", prefix, app.players().len())
+            }
+            // A partially fetched season is not saved, so the next run fetches it again
+            Ok(()) => {
+                failed_seasons += 1;
+                println!(
+                    "{}",
+                    format!(
+                        "{}: {} of {} competitors failed, the season is not archived",
+                        prefix, last.failed, last.total
+                    )
+                    .red()
+                );
+            }
+            Err(err) => {
+                failed_seasons += 1;
+                println!("{}", format!("{}: {}", prefix, err).red());
+            }
+        }
+    }
+
+    if failed_seasons > 0 {
+        return Err(format!(
+            "{} seasons were not archived, run the command again to resume",
+            failed_seasons
+        )
+        .into());
+    }
+
+    Ok(())
+}
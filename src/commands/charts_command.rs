@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct ChartsArgs {
+    /// Id of the cached season (defaults to the most recently loaded season)
+    #[arg(long)]
+    pub season: Option<String>,
+
+    /// Directory to write the charts into
+    #[arg(long, value_name = "DIR", default_value = "charts")]
+    pub output: PathBuf,
+
+    /// Number of players on the leaderboard charts
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+
+    /// Id of a player to draw the radar chart of (can be repeated, defaults to the players on the
+    /// leaderboards)
+    #[arg(long = "player", value_name = "ID")]
+    pub players: Vec<String>,
+}
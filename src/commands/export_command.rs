@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Write the seasons into this SQLite database, updating the rows exported before
+    #[arg(long, value_name = "PATH")]
+    pub sqlite: Option<PathBuf>,
+
+    /// Write a workbook per season into this directory, with the leaderboards, the league table,
+    /// the player statistics, and the fixtures
+    #[arg(long, value_name = "DIR")]
+    pub xlsx: Option<PathBuf>,
+
+    /// Number of players on the top scorers and top assistants sheets of the workbook
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+
+    /// Write the player and team season statistics as Parquet files into this directory
+    #[arg(long, value_name = "DIR")]
+    pub parquet: Option<PathBuf>,
+
+    /// Write the player and team season statistics as Arrow IPC files into this directory
+    #[arg(long, value_name = "DIR")]
+    pub arrow: Option<PathBuf>,
+
+    /// Write the player and team season statistics as CSV files into this directory
+    #[arg(long, value_name = "DIR")]
+    pub csv: Option<PathBuf>,
+
+    /// Id of the cached season to export (defaults to the most recently loaded season)
+    #[arg(long, conflicts_with = "all_seasons")]
+    pub season: Option<String>,
+
+    /// Export all the seasons in the local cache
+    #[arg(long)]
+    pub all_seasons: bool,
+}
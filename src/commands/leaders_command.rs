@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use clap::Args;
+use colorize::AnsiColor;
+
+use sportradar::{
+    enums::Players,
+    helpers::{get_cache_dir, load_cached_season, top_players},
+    models::SeasonData,
+    services::{BaseInfoService, PlayerService},
+};
+
+use crate::interactive::{LeaderboardRow, TableStyle};
+
+#[derive(Debug, Args)]
+pub struct LeadersArgs {
+    /// Id of the cached season (defaults to the most recently loaded season)
+    #[arg(long)]
+    pub season: Option<String>,
+
+    /// Statistics to rank the players by, `goals` or `assists`
+    #[arg(long, default_value = "goals")]
+    pub metric: Players,
+
+    /// Number of players to show
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+}
+
+/// Prints the leaderboard of a season from the local cache as a table in the style, without
+/// calling the API.
+pub fn leaders(args: &LeadersArgs, style: &TableStyle) -> Result<(), Box<dyn Error>> {
+    let snapshot: SeasonData = load_cached_season(&get_cache_dir(), args.season.as_deref())?;
+
+    let title = format!(
+        "{} - {}: {}",
+        snapshot.competition, snapshot.season, args.metric
+    );
+    println!(
+        "{}",
+        if style.color {
+            title.green().bold()
+        } else {
+            title
+        }
+    );
+
+    let rows: Vec<LeaderboardRow> = top_players(&snapshot.players, args.metric, args.limit)
+        .iter()
+        .enumerate()
+        .map(|(index, player)| LeaderboardRow {
+            rank: index + 1,
+            player: player.name(),
+            team: player.team().name(),
+            value: args.metric.season_value(player),
+        })
+        .collect();
+    println!(
+        "{}",
+        style.render_leaderboard(args.metric.column_title(), &rows)
+    );
+
+    Ok(())
+}
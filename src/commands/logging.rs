@@ -0,0 +1,49 @@
+use clap::ValueEnum;
+use tracing::Level;
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// The format of the log output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Human readable lines
+    #[default]
+    Text,
+    /// One JSON object per line, with the fields of the current spans
+    Json,
+}
+
+// The log levels in the order of the increasing verbosity
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// Writes the logs of the application to stderr. Every `-v` raises the level of the application
+/// logs by one step from the default level, the `RUST_LOG` variable (e.g.
+/// `RUST_LOG=sportradar=debug`) overrides it. The logs of the dependencies show the errors only,
+/// unless `RUST_LOG` says otherwise.
+///
+/// The spans are logged when they close, so the `api_request` spans are logged with their status,
+/// latency, retries, and payload size.
+pub fn init_logging(verbose: u8, format: LogFormat, default_level: Level) {
+    let default_index = LEVELS
+        .iter()
+        .position(|level| *level == default_level)
+        .unwrap_or(1);
+    let level = LEVELS[(default_index + verbose as usize).min(LEVELS.len() - 1)];
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(format!("error,sportradar={}", level)));
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+
+    match format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().with_span_list(true).init(),
+    }
+}
@@ -0,0 +1,34 @@
+use std::{net::SocketAddr, path::PathBuf};
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct MockArgs {
+    /// Fixture directory with the responses to serve (recorded with the `--record` option)
+    pub fixtures: PathBuf,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8081")]
+    pub address: SocketAddr,
+
+    /// Respond to the matching requests with this HTTP status instead of the fixture, e.g. 429
+    /// or 503
+    #[arg(long)]
+    pub status: Option<u16>,
+
+    /// Respond to the matching requests with a truncated, malformed body
+    #[arg(long)]
+    pub malformed: bool,
+
+    /// Delay the matching responses by this many milliseconds
+    #[arg(long, default_value_t = 0)]
+    pub delay_ms: u64,
+
+    /// Inject the faults only into the first N matching requests (all of them by default)
+    #[arg(long)]
+    pub fault_count: Option<usize>,
+
+    /// Inject the faults only into the requests whose path contains this text
+    #[arg(long)]
+    pub fault_path: Option<String>,
+}
@@ -0,0 +1,118 @@
+mod archive_command;
+mod charts_command;
+mod export_command;
+mod leaders_command;
+mod logging;
+mod mock_command;
+mod quota_command;
+mod raw_command;
+mod report_command;
+mod search_command;
+mod serve_command;
+
+use std::path::PathBuf;
+
+use clap::{ArgAction, Parser, Subcommand};
+
+use crate::interactive::ColorTheme;
+
+pub use archive_command::{archive, ArchiveArgs};
+pub use charts_command::ChartsArgs;
+pub use export_command::ExportArgs;
+pub use leaders_command::{leaders, LeadersArgs};
+pub use logging::{init_logging, LogFormat};
+pub use mock_command::MockArgs;
+pub use quota_command::{show_quota, QuotaArgs};
+pub use raw_command::{raw, RawArgs};
+pub use report_command::ReportArgs;
+pub use search_command::{search, SearchArgs};
+pub use serve_command::ServeArgs;
+
+/// SportRadar CLI: explore the soccer statistics provided by the Sportradar API.
+///
+/// Run without a command to start the interactive mode.
+#[derive(Debug, Parser)]
+#[command(name = "sportradar", version)]
+pub struct Cli {
+    /// Name of the sport API to use, e.g. `soccer` (defaults to all the configured sports in the
+    /// interactive mode and to the first configured sport otherwise)
+    #[arg(long, global = true)]
+    pub sport: Option<String>,
+
+    /// Save the API responses (without the API key) into this fixture directory
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Serve the API responses from this fixture directory instead of calling the API
+    #[arg(long, global = true, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+
+    /// Write the report of the API responses not matching the models (skipped items, missing and
+    /// unexpected fields) to this JSON file on exit
+    #[arg(long, global = true, value_name = "FILE")]
+    pub diagnostics: Option<PathBuf>,
+
+    /// Print the API requests loading a season would send and exit without fetching the season
+    /// (the interactive mode only)
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Log more details to stderr (`-v` for the fetch steps, `-vv` for every API request, `-vvv`
+    /// for everything). The `RUST_LOG` variable overrides it
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Format of the logs
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+
+    /// Colour theme of the leaderboard tables (no colours if `NO_COLOR` is set or the output is
+    /// not a terminal)
+    #[arg(long, global = true, value_enum, default_value_t = ColorTheme::Classic)]
+    pub theme: ColorTheme,
+
+    /// Draw a bar chart of the values next to the leaderboard tables
+    #[arg(long, global = true)]
+    pub bars: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Search players and teams by a partial name in the locally cached seasons
+    Search(SearchArgs),
+
+    /// Start the full-screen terminal dashboard
+    Tui,
+
+    /// Start an HTTP server exposing the competitions, seasons, and leaderboards as JSON
+    Serve(ServeArgs),
+
+    /// Start a mock Sportradar API serving the recorded fixtures, with injectable faults
+    Mock(MockArgs),
+
+    /// Show the number of the API requests sent this month and the monthly budget left
+    Quota(QuotaArgs),
+
+    /// Fetch the raw response of an API endpoint with the configured key, format, and retries
+    Raw(RawArgs),
+
+    /// Export the seasons from the local cache, e.g. into a SQLite database
+    Export(ExportArgs),
+
+    /// Fetch every season of a competition into the local cache, resuming an interrupted archive
+    Archive(ArchiveArgs),
+
+    /// Show the top goal scorers or assistants of a season from the local cache
+    Leaders(LeadersArgs),
+
+    /// Render the HTML report of a season from the local cache, with the league table, the
+    /// leaderboards, and the team and player pages
+    Report(ReportArgs),
+
+    /// Write the SVG charts of a season from the local cache: the leaderboards and the player
+    /// percentile profiles
+    Charts(ChartsArgs),
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+
+use clap::Args;
+use colorize::AnsiColor;
+
+use sportradar::helpers::{
+    api_key_id, current_month, get_api_key, get_monthly_budget, get_quota_file, quota_usage,
+};
+
+#[derive(Debug, Args)]
+pub struct QuotaArgs {
+    /// Month to show, as `YYYY-MM` (defaults to the current month, UTC)
+    #[arg(long)]
+    pub month: Option<String>,
+
+    /// Show the usage of all the API keys, not only of the configured one
+    #[arg(long)]
+    pub all_keys: bool,
+}
+
+/// Prints the number of the API requests sent in the month, by the day and the endpoint, and the
+/// monthly budget left.
+pub fn show_quota(args: &QuotaArgs) -> Result<(), Box<dyn Error>> {
+    let month = args.month.clone().unwrap_or_else(current_month);
+    let usage = quota_usage(&get_quota_file());
+    let budget = get_monthly_budget()?;
+    let current_key = get_api_key().ok().map(|api_key| api_key_id(&api_key));
+
+    let keys: Vec<&String> = match (&current_key, args.all_keys) {
+        (Some(key), false) => vec![key],
+        _ => usage.keys.keys().collect(),
+    };
+    if keys.is_empty() {
+        println!("No API requests recorded yet");
+        return Ok(());
+    }
+
+    for key in keys {
+        let total = usage.month_total(key, &month);
+        let budget = match budget {
+            // The budget is set for the configured key only
+            Some(budget) if current_key.as_ref() == Some(key) => format!(
+                " (budget {}, {} left)",
+                budget,
+                budget.saturating_sub(total)
+            ),
+            _ => String::new(),
+        };
+        println!(
+            "{}",
+            format!(
+                "API key {}: {} requests in {}{}",
+                usage.display_key(key),
+                total,
+                month,
+                budget
+            )
+            .green()
+            .bold()
+        );
+        if total == 0 {
+            continue;
+        }
+
+        println!("{}", "By day:".yellow().bold());
+        for (day, day_usage) in usage.month_days(key, &month) {
+            println!("  {}  {}", day, day_usage.values().sum::<u64>());
+        }
+
+        println!("{}", "By endpoint:".yellow().bold());
+        for (endpoint, count) in usage.month_endpoints(key, &month) {
+            println!("  {:<30}  {}", endpoint, count);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use clap::Args;
+
+use sportradar::{
+    enums::ResponseFormat, helpers::select_json, services::ApiService, SportradarClient,
+};
+
+#[derive(Debug, Args)]
+pub struct RawArgs {
+    /// Endpoint path of the sport API, e.g. `seasons/sr:season:105353/info` (the format
+    /// extension is optional, the query parameters are kept)
+    pub path: String,
+
+    /// Part of the JSON response to show, as a jq-style selector, e.g.
+    /// `.competitor.players[].name`
+    #[arg(long)]
+    pub select: Option<String>,
+
+    /// Save the response to this file instead of printing it
+    #[arg(long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+/// Fetches the raw response of an endpoint with the configured API settings and HTTP client and
+/// prints it (the JSON responses pretty-printed) or saves it to a file.
+pub async fn raw<HttpClient: ApiService + Clone>(
+    client: &SportradarClient<HttpClient>,
+    args: &RawArgs,
+) -> Result<(), Box<dyn Error>> {
+    let body = client.fetch_raw(&args.path).await?;
+
+    let output = match (client.config().format, &args.select) {
+        (ResponseFormat::Json, select) => {
+            let value: serde_json::Value = serde_json::from_str(&body)?;
+            let value = match select {
+                Some(selector) => select_json(&value, selector)?,
+                None => value,
+            };
+            serde_json::to_string_pretty(&value)?
+        }
+        (ResponseFormat::Xml, Some(_)) => {
+            return Err("The --select option works with the JSON responses only".into())
+        }
+        (ResponseFormat::Xml, None) => body,
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output)?;
+            eprintln!("Response saved to {}", path.display());
+        }
+        None => println!("{}", output),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// Id of the cached season (defaults to the most recently loaded season)
+    #[arg(long)]
+    pub season: Option<String>,
+
+    /// Render the report with this Jinja template file, or a built-in template: `slack`, `email`,
+    /// or `markdown`
+    #[arg(long, value_name = "FILE")]
+    pub template: Option<String>,
+
+    /// Directory to write the HTML report into (`report` by default), or the file to write the
+    /// templated report into (stdout by default)
+    #[arg(long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Number of players on the leaderboards
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+}
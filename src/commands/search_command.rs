@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use clap::Args;
+use colorize::AnsiColor;
+
+use sportradar::{
+    helpers::{get_cache_dir, load_cached_season, load_cached_seasons, rank_matches},
+    models::{CompetitionSeason, Player, SeasonData, Team},
+    services::{BaseInfoService, PlayerSeasonStatisticsService},
+};
+
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Partial player or team name (case and diacritics are ignored)
+    pub query: String,
+
+    /// Id of the cached season to search in (defaults to the most recently loaded season)
+    #[arg(long, conflicts_with = "all_seasons")]
+    pub season: Option<String>,
+
+    /// Search across all the seasons in the local cache
+    #[arg(long)]
+    pub all_seasons: bool,
+
+    /// Maximum number of players and teams to show
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+}
+
+/// Searches players and teams by a partial name in the seasons saved to the local cache and
+/// prints the best matches.
+pub fn search(args: &SearchArgs) -> Result<(), Box<dyn Error>> {
+    if args.all_seasons {
+        return search_all_seasons(args);
+    }
+
+    let snapshot: SeasonData = load_cached_season(&get_cache_dir(), args.season.as_deref())?;
+    println!(
+        "{}",
+        format!("{} - {}", snapshot.competition, snapshot.season)
+            .green()
+            .bold()
+    );
+
+    let players = rank_matches(&snapshot.players, &args.query, |player| player.name());
+    print_players(
+        players
+            .into_iter()
+            .take(args.limit)
+            .map(|player| (player, None)),
+    );
+    let teams = rank_matches(&snapshot.competitors, &args.query, |team| team.name());
+    print_teams(teams.into_iter().take(args.limit));
+
+    Ok(())
+}
+
+// Search in every cached season, showing the season of each found player
+fn search_all_seasons(args: &SearchArgs) -> Result<(), Box<dyn Error>> {
+    let snapshots: Vec<SeasonData> = load_cached_seasons(&get_cache_dir(), None, true)?;
+
+    let players = snapshots.iter().flat_map(|snapshot| {
+        snapshot
+            .players
+            .iter()
+            .map(move |player| (player, Some(&snapshot.season)))
+    });
+    let players = rank_matches(players, &args.query, |(player, _)| player.name());
+    print_players(players.into_iter().take(args.limit));
+
+    // The same team plays in many seasons, show it only once
+    let mut teams: Vec<&Team> = snapshots
+        .iter()
+        .flat_map(|snapshot| snapshot.competitors.iter())
+        .collect();
+    teams.sort_by_key(|team| team.id());
+    teams.dedup_by_key(|team| team.id());
+    let teams = rank_matches(teams, &args.query, |team| team.name());
+    print_teams(teams.into_iter().take(args.limit));
+
+    Ok(())
+}
+
+fn print_players<'a>(players: impl Iterator<Item = (&'a Player, Option<&'a CompetitionSeason>)>) {
+    println!("{}", "Players:".yellow().bold());
+    for (index, (player, season)) in players.enumerate() {
+        let season = season
+            .map(|season| format!(" [{}]", season))
+            .unwrap_or_default();
+        println!(
+            "{}. {}{} - {} goals, {} assists",
+            index + 1,
+            player,
+            season,
+            player.season_goals(),
+            player.season_assists()
+        );
+    }
+}
+
+fn print_teams<'a>(teams: impl Iterator<Item = &'a Team>) {
+    println!("{}", "Teams:".yellow().bold());
+    for (index, team) in teams.enumerate() {
+        println!("{}. {}", index + 1, team);
+    }
+}
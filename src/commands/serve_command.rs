@@ -0,0 +1,14 @@
+use std::net::SocketAddr;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    pub address: SocketAddr,
+
+    /// How long the fetched data is cached, in seconds
+    #[arg(long, default_value_t = 3600)]
+    pub cache_ttl: u64,
+}
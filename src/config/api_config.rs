@@ -0,0 +1,363 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use url::form_urlencoded;
+
+use crate::{
+    enums::{Endpoint, ResponseFormat},
+    helpers::{
+        get_access_level, get_api_base_url, get_api_key, get_cache_dir, get_format,
+        get_language_code, get_monthly_budget, get_quota_file, get_rate_limit,
+        get_sports_config_path,
+    },
+    services::SportApiService,
+};
+
+use super::{EndpointSettings, SportApi};
+
+/// The settings of the Sportradar API used to build the request URLs.
+///
+/// Use [`ApiConfig::from_env`] to read the settings from the environment variables (the way the
+/// CLI does) or construct it directly and adjust the fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiConfig {
+    /// The base URL of the API, e.g. `https://api.sportradar.com`.
+    pub base_url: String,
+    /// The API key sent with every request.
+    pub api_key: String,
+    /// The access level of the API key, e.g. `trial` or `production`.
+    pub access_level: String,
+    /// The language code of the response texts, e.g. `en`.
+    pub language_code: String,
+    /// The response format, JSON or XML.
+    pub format: ResponseFormat,
+    /// The sport APIs reachable with the API key.
+    pub sports: Vec<SportApi>,
+    /// The settings of single endpoints, by the endpoint name (see [`Endpoint::name`]). They apply
+    /// to all the sports, the endpoint settings of a [`SportApi`] take precedence over them.
+    pub endpoints: BTreeMap<String, EndpointSettings>,
+    /// The number of the API requests allowed per calendar month (UTC), `None` for no limit. The
+    /// fetches which would go over it are refused (see [`crate::helpers::check_quota_budget`]).
+    pub monthly_budget: Option<u64>,
+    /// The number of the requests per second allowed for the API key (1 for the trial keys), used
+    /// to estimate how long a fetch takes (see [`crate::models::RequestPlan`]) and to throttle the
+    /// long fetches (see [`crate::client::RateLimitedClient`]).
+    pub rate_limit: u32,
+    /// The directory of the local cache, where the season snapshots are saved (see
+    /// [`crate::helpers::save_season_snapshot`]).
+    pub cache_dir: PathBuf,
+    /// The local state file counting the sent API requests (see
+    /// [`crate::helpers::quota_usage`]).
+    pub quota_file: PathBuf,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.sportradar.com".to_string(),
+            api_key: String::new(),
+            access_level: "trial".to_string(),
+            language_code: "en".to_string(),
+            format: ResponseFormat::Json,
+            sports: vec![SportApi::soccer()],
+            endpoints: BTreeMap::new(),
+            monthly_budget: None,
+            rate_limit: 1,
+            cache_dir: PathBuf::from(".sportradar_cache"),
+            quota_file: PathBuf::from(".sportradar_cache/quota.json"),
+        }
+    }
+}
+
+impl ApiConfig {
+    /// Reads the settings from the `SPORT_RADAR_*` environment variables.
+    ///
+    /// The sport APIs are loaded from the JSON file set by `SPORT_RADAR_SPORTS_CONFIG`. Without it,
+    /// only the Soccer API is used, showing the competitions from the `ALLOWED_COMPETITIONS` and
+    /// `ALLOWED_COUNTRIES` variables. The endpoint settings are read with
+    /// [`EndpointSettings::from_env`]. The local cache is kept in `SPORT_RADAR_CACHE_DIR`
+    /// (`.sportradar_cache` by default) and the API usage in `SPORT_RADAR_QUOTA_FILE` (`quota.json`
+    /// in the cache directory by default).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the required variables (the API key, the base URL, or the access
+    /// level) is not set, the response format is neither `json` nor `xml`, the monthly budget
+    /// (`SPORT_RADAR_MONTHLY_BUDGET`) or the rate limit (`SPORT_RADAR_RATE_LIMIT`) is not a
+    /// number, or the sports config cannot be loaded.
+    pub fn from_env() -> Result<Self, String> {
+        let sports = match get_sports_config_path() {
+            Some(path) => SportApi::load_all(path).map_err(|err| err.to_string())?,
+            None => vec![SportApi::soccer_from_env()],
+        };
+
+        let endpoints = Endpoint::names()
+            .into_iter()
+            .filter_map(|name| Some((name.to_string(), EndpointSettings::from_env(name)?)))
+            .collect();
+
+        Ok(Self {
+            base_url: get_api_base_url()?,
+            api_key: get_api_key()?,
+            access_level: get_access_level()?,
+            language_code: get_language_code(),
+            format: get_format().parse()?,
+            sports,
+            endpoints,
+            monthly_budget: get_monthly_budget()?,
+            rate_limit: get_rate_limit()?,
+            cache_dir: get_cache_dir(),
+            quota_file: get_quota_file(),
+        })
+    }
+
+    /// Returns the sport API with the given name in the URL (e.g. `soccer`), or the first configured
+    /// sport API if no name is given.
+    pub fn find_sport(&self, api: Option<&str>) -> Result<&SportApi, String> {
+        match api {
+            Some(api) => self
+                .sports
+                .iter()
+                .find(|sport| sport.api == api)
+                .ok_or_else(|| format!("Sport API '{}' is not configured", api)),
+            None => self.sports.first().ok_or("No sports are configured".into()),
+        }
+    }
+
+    /// Returns the settings of the endpoint for the sport: the settings set for the endpoint by the
+    /// sport API, then the ones set for the endpoint by this config, then the sport API's version and
+    /// this config's access level and language code.
+    pub fn endpoint_settings(&self, sport_api: &SportApi, endpoint: &Endpoint) -> EndpointSettings {
+        self.settings_of_endpoint_name(sport_api, Some(endpoint.name()))
+    }
+
+    // Get the settings of the endpoint with the given name, or the default settings of the sport if
+    // the endpoint is not known
+    fn settings_of_endpoint_name(
+        &self,
+        sport_api: &SportApi,
+        endpoint_name: Option<&str>,
+    ) -> EndpointSettings {
+        let overrides = endpoint_name
+            .map(|name| [sport_api.endpoints.get(name), self.endpoints.get(name)])
+            .unwrap_or_default();
+        let setting = |get: fn(&EndpointSettings) -> &Option<String>, default: &String| {
+            overrides
+                .iter()
+                .flatten()
+                .find_map(|settings| get(settings).clone())
+                .unwrap_or_else(|| default.clone())
+        };
+
+        EndpointSettings {
+            version: Some(setting(|settings| &settings.version, &sport_api.version)),
+            access_level: Some(setting(
+                |settings| &settings.access_level,
+                &self.access_level,
+            )),
+            language_code: Some(setting(
+                |settings| &settings.language_code,
+                &self.language_code,
+            )),
+        }
+    }
+
+    /// Builds the URL of the sport's API endpoint from the sport's URL template, with the API key
+    /// and the endpoint's query parameters.
+    pub fn api_url<T: SportApiService>(&self, sport: &T, endpoint: &Endpoint) -> String {
+        let query = endpoint
+            .query()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        self.build_url(
+            sport.sport_api(),
+            Some(endpoint.name()),
+            &endpoint.path(),
+            query,
+        )
+    }
+
+    /// Builds the URL of a raw endpoint path of the sport (e.g. `seasons/sr:season:105353/info`,
+    /// the leading slash and the format extension are optional) the same way as
+    /// [`ApiConfig::api_url`]: the settings of the endpoint matching the path apply (see
+    /// [`Endpoint::name_of_path`]), and the query parameters of the path are kept.
+    pub fn raw_api_url<T: SportApiService>(&self, sport: &T, path: &str) -> String {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let path = path.trim_matches('/');
+        let path = [ResponseFormat::Json, ResponseFormat::Xml]
+            .iter()
+            .find_map(|format| path.strip_suffix(&format!(".{}", format)))
+            .unwrap_or(path);
+        // The query parameters are decoded here and encoded again with the API key
+        let query = form_urlencoded::parse(query.as_bytes())
+            .filter(|(name, _)| name != "api_key")
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+
+        self.build_url(sport.sport_api(), Endpoint::name_of_path(path), path, query)
+    }
+
+    // Build the URL of the path from the sport's URL template, with the API key and the query
+    // parameters (percent-encoded)
+    fn build_url(
+        &self,
+        sport_api: &SportApi,
+        endpoint_name: Option<&str>,
+        path: &str,
+        query: Vec<(String, String)>,
+    ) -> String {
+        let settings = self.settings_of_endpoint_name(sport_api, endpoint_name);
+        let url = sport_api
+            .url_template()
+            .replace("{base_url}", &self.base_url)
+            .replace("{api}", &sport_api.api)
+            .replace("{access_level}", &settings.access_level.unwrap_or_default())
+            .replace("{version}", &settings.version.unwrap_or_default())
+            .replace(
+                "{language_code}",
+                &settings.language_code.unwrap_or_default(),
+            )
+            .replace("{endpoint}", path)
+            .replace("{format}", &self.format.to_string());
+
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("api_key", &self.api_key)
+            .extend_pairs(query)
+            .finish();
+
+        format!("{}?{}", url, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Sport;
+
+    fn config() -> ApiConfig {
+        ApiConfig {
+            api_key: "secret".to_string(),
+            ..ApiConfig::default()
+        }
+    }
+
+    fn soccer() -> Sport {
+        Sport::from_sport_api(&SportApi::soccer())
+    }
+
+    fn player_profile() -> Endpoint {
+        Endpoint::PlayerProfile {
+            player_id: "sr:player:159665".to_string(),
+        }
+    }
+
+    #[test]
+    fn api_url_of_endpoint_with_query() {
+        let endpoint = Endpoint::SeasonSchedules {
+            season_id: "sr:season:105353".to_string(),
+            start: Some(200),
+            limit: Some(100),
+        };
+
+        assert_eq!(
+            config().api_url(&soccer(), &endpoint),
+            "https://api.sportradar.com/soccer/trial/v4/en/seasons/sr:season:105353/schedules.json\
+             ?api_key=secret&start=200&limit=100"
+        );
+    }
+
+    #[test]
+    fn api_url_percent_encodes_query() {
+        let config = ApiConfig {
+            api_key: "a+b/c d&e".to_string(),
+            ..config()
+        };
+
+        assert_eq!(
+            config.raw_api_url(&soccer(), "competitions?q=%C3%98degaard&x=1%262"),
+            "https://api.sportradar.com/soccer/trial/v4/en/competitions.json\
+             ?api_key=a%2Bb%2Fc+d%26e&q=%C3%98degaard&x=1%262"
+        );
+    }
+
+    #[test]
+    fn sport_endpoint_version_overrides_sport_version() {
+        let mut sport_api = SportApi::soccer();
+        sport_api.endpoints.insert(
+            "player_profile".to_string(),
+            EndpointSettings {
+                version: Some("v3".to_string()),
+                ..EndpointSettings::default()
+            },
+        );
+        let sport = Sport::from_sport_api(&sport_api);
+
+        assert_eq!(
+            config().api_url(&sport, &player_profile()),
+            "https://api.sportradar.com/soccer/trial/v3/en/players/sr:player:159665/profile.json\
+             ?api_key=secret"
+        );
+        // The other endpoints keep the sport's version
+        assert_eq!(
+            config().api_url(&sport, &Endpoint::Competitions),
+            "https://api.sportradar.com/soccer/trial/v4/en/competitions.json?api_key=secret"
+        );
+    }
+
+    #[test]
+    fn sport_endpoint_settings_take_precedence_over_config_ones() {
+        let mut config = config();
+        config.endpoints.insert(
+            "player_profile".to_string(),
+            EndpointSettings {
+                version: Some("v2".to_string()),
+                access_level: Some("production".to_string()),
+                language_code: None,
+            },
+        );
+        let mut sport_api = SportApi::soccer();
+        sport_api.endpoints.insert(
+            "player_profile".to_string(),
+            EndpointSettings {
+                version: Some("v3".to_string()),
+                ..EndpointSettings::default()
+            },
+        );
+
+        assert_eq!(
+            config.endpoint_settings(&sport_api, &player_profile()),
+            EndpointSettings {
+                version: Some("v3".to_string()),
+                access_level: Some("production".to_string()),
+                language_code: Some("en".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn raw_api_url_applies_settings_of_matching_endpoint() {
+        let mut config = config();
+        config.endpoints.insert(
+            "player_profile".to_string(),
+            EndpointSettings {
+                access_level: Some("production".to_string()),
+                ..EndpointSettings::default()
+            },
+        );
+
+        assert_eq!(
+            config.raw_api_url(
+                &soccer(),
+                "/players/sr:player:159665/profile.xml?api_key=old"
+            ),
+            config.api_url(&soccer(), &player_profile())
+        );
+        // An unknown path gets the default settings
+        assert_eq!(
+            config.raw_api_url(&soccer(), "sport_events/sr:sport_event:1/summary"),
+            "https://api.sportradar.com/soccer/trial/v4/en/sport_events/sr:sport_event:1/summary.json\
+             ?api_key=secret"
+        );
+    }
+}
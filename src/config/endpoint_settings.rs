@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::get_endpoint_setting;
+
+/// The settings overriding the API-wide settings for a single endpoint. The settings which are
+/// not set fall back to the sport API's version and the API config's access level and language.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct EndpointSettings {
+    pub version: Option<String>,
+    pub access_level: Option<String>,
+    pub language_code: Option<String>,
+}
+
+impl EndpointSettings {
+    /// Reads the settings of the endpoint from the `SPORT_RADAR_<ENDPOINT>_VERSION`,
+    /// `SPORT_RADAR_<ENDPOINT>_ACCESS_LEVEL`, and `SPORT_RADAR_<ENDPOINT>_LANGUAGE_CODE`
+    /// environment variables, where `<ENDPOINT>` is the uppercased endpoint name (e.g.
+    /// `SPORT_RADAR_PLAYER_PROFILE_VERSION`). Returns `None` if none of them is set.
+    pub fn from_env(endpoint_name: &str) -> Option<Self> {
+        let settings = Self {
+            version: get_endpoint_setting(endpoint_name, "VERSION"),
+            access_level: get_endpoint_setting(endpoint_name, "ACCESS_LEVEL"),
+            language_code: get_endpoint_setting(endpoint_name, "LANGUAGE_CODE"),
+        };
+
+        (settings != Self::default()).then_some(settings)
+    }
+}
@@ -0,0 +1,7 @@
+mod api_config;
+mod endpoint_settings;
+mod sport_api;
+
+pub use api_config::ApiConfig;
+pub use endpoint_settings::EndpointSettings;
+pub use sport_api::{SportApi, DEFAULT_URL_TEMPLATE};
@@ -0,0 +1,94 @@
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::{get_allowed_competitions, get_allowed_countries};
+
+use super::EndpointSettings;
+
+/// The URL template used when a sport does not define its own one.
+pub const DEFAULT_URL_TEMPLATE: &str =
+    "{base_url}/{api}/{access_level}/{version}/{language_code}/{endpoint}.{format}";
+
+/// The description of a Sportradar sport API reachable with the API key: its name in the URL, its
+/// version, and the competitions of the sport to show.
+///
+/// The sport APIs are listed in a JSON file (see `sports.example.json`) whose path is set by the
+/// `SPORT_RADAR_SPORTS_CONFIG` environment variable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SportApi {
+    /// The Sportradar id of the sport, e.g. `sr:sport:1`.
+    pub id: String,
+    /// The display name of the sport, e.g. `Soccer`.
+    pub name: String,
+    /// The name of the API in the URL path, e.g. `soccer`.
+    pub api: String,
+    /// The API version, e.g. `v4`.
+    pub version: String,
+    /// The template of the endpoint URLs (see [`DEFAULT_URL_TEMPLATE`] for the placeholders).
+    #[serde(default)]
+    pub url_template: Option<String>,
+    /// Only the competitions with these names are shown (`None` shows all the competitions).
+    #[serde(default)]
+    pub allowed_competitions: Option<Vec<String>>,
+    /// Only the competitions from these countries (categories) are shown (`None` shows all of them).
+    #[serde(default)]
+    pub allowed_countries: Option<Vec<String>>,
+    /// The settings of single endpoints of the sport API, by the endpoint name (see
+    /// [`crate::enums::Endpoint::name`]), e.g. an older version of the player profile endpoint.
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, EndpointSettings>,
+}
+
+impl SportApi {
+    /// The Soccer API v4 without any competition filters.
+    pub fn soccer() -> Self {
+        Self {
+            id: "sr:sport:1".to_string(),
+            name: "Soccer".to_string(),
+            api: "soccer".to_string(),
+            version: "v4".to_string(),
+            url_template: None,
+            allowed_competitions: None,
+            allowed_countries: None,
+            endpoints: BTreeMap::new(),
+        }
+    }
+
+    /// The Soccer API v4 showing only the competitions from the `ALLOWED_COMPETITIONS` and
+    /// `ALLOWED_COUNTRIES` environment variables (the competitions with the official statistics
+    /// available in the trial version of the API).
+    pub fn soccer_from_env() -> Self {
+        Self {
+            allowed_competitions: Some(get_allowed_competitions()),
+            allowed_countries: Some(get_allowed_countries()),
+            ..Self::soccer()
+        }
+    }
+
+    /// Loads the list of the sport APIs from a JSON file.
+    pub fn load_all(path: impl AsRef<Path>) -> Result<Vec<Self>, Box<dyn Error>> {
+        let path = path.as_ref();
+        let data = fs::read(path)
+            .map_err(|err| format!("Cannot read the sports config {}: {}", path.display(), err))?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /// Returns the URL template of the sport API.
+    pub fn url_template(&self) -> &str {
+        self.url_template.as_deref().unwrap_or(DEFAULT_URL_TEMPLATE)
+    }
+
+    /// Checks whether a competition with the given name and country passes the sport's allowed
+    /// competitions and countries filters.
+    pub fn is_competition_allowed(&self, name: &str, country: &str) -> bool {
+        let is_allowed = |allowed: &Option<Vec<String>>, value: &str| {
+            allowed
+                .as_ref()
+                .is_none_or(|allowed| allowed.iter().any(|item| item == value))
+        };
+
+        is_allowed(&self.allowed_competitions, name) && is_allowed(&self.allowed_countries, country)
+    }
+}
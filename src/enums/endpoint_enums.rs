@@ -0,0 +1,277 @@
+use std::fmt::Display;
+
+/// The Sportradar API endpoints used by the application. Every endpoint knows its path and its
+/// query parameters, so the URLs are built in one place (see [`crate::ApiConfig::api_url`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// All the competitions of the sport.
+    Competitions,
+    /// The details of a competition.
+    CompetitionInfo { competition_id: String },
+    /// All the seasons of a competition.
+    CompetitionSeasons { competition_id: String },
+    /// The details of a season.
+    SeasonInfo { season_id: String },
+    /// The competitors (teams) of a season.
+    SeasonCompetitors { season_id: String },
+    /// The sport events of a season. The optional `start` and `limit` page the results.
+    SeasonSchedules {
+        season_id: String,
+        start: Option<usize>,
+        limit: Option<usize>,
+    },
+    /// The statistics of a competitor and its players in a season.
+    SeasonCompetitorStatistics {
+        season_id: String,
+        competitor_id: String,
+    },
+    /// The profile of a competitor.
+    CompetitorProfile { competitor_id: String },
+    /// The profile of a player.
+    PlayerProfile { player_id: String },
+}
+
+impl Endpoint {
+    /// Returns the names of all the endpoints, in the order of the enum variants.
+    pub fn names() -> [&'static str; 9] {
+        let id = String::new;
+        [
+            Endpoint::Competitions,
+            Endpoint::CompetitionInfo {
+                competition_id: id(),
+            },
+            Endpoint::CompetitionSeasons {
+                competition_id: id(),
+            },
+            Endpoint::SeasonInfo { season_id: id() },
+            Endpoint::SeasonCompetitors { season_id: id() },
+            Endpoint::SeasonSchedules {
+                season_id: id(),
+                start: None,
+                limit: None,
+            },
+            Endpoint::SeasonCompetitorStatistics {
+                season_id: id(),
+                competitor_id: id(),
+            },
+            Endpoint::CompetitorProfile {
+                competitor_id: id(),
+            },
+            Endpoint::PlayerProfile { player_id: id() },
+        ]
+        .map(|endpoint| endpoint.name())
+    }
+
+    /// Returns the name of the endpoint (one of [`Endpoint::names`]), used to configure the
+    /// endpoint's settings.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Endpoint::Competitions => "competitions",
+            Endpoint::CompetitionInfo { .. } => "competition_info",
+            Endpoint::CompetitionSeasons { .. } => "competition_seasons",
+            Endpoint::SeasonInfo { .. } => "season_info",
+            Endpoint::SeasonCompetitors { .. } => "season_competitors",
+            Endpoint::SeasonSchedules { .. } => "season_schedules",
+            Endpoint::SeasonCompetitorStatistics { .. } => "season_competitor_statistics",
+            Endpoint::CompetitorProfile { .. } => "competitor_profile",
+            Endpoint::PlayerProfile { .. } => "player_profile",
+        }
+    }
+
+    /// Returns the name of the endpoint whose URL has the given path, e.g. `season_schedules` for
+    /// `/soccer/trial/v4/en/seasons/sr:season:105353/schedules.json`, or `None` if the path is not
+    /// one of the endpoints.
+    pub fn name_of_path(path: &str) -> Option<&'static str> {
+        let path = path.rsplit_once('.').map_or(path, |(path, _format)| path);
+        let segments: Vec<&str> = path.split('/').collect();
+
+        let name = match segments.as_slice() {
+            [.., "seasons", _, "competitors", _, "statistics"] => "season_competitor_statistics",
+            [.., "competitions", _, "info"] => "competition_info",
+            [.., "competitions", _, "seasons"] => "competition_seasons",
+            [.., "seasons", _, "info"] => "season_info",
+            [.., "seasons", _, "competitors"] => "season_competitors",
+            [.., "seasons", _, "schedules"] => "season_schedules",
+            [.., "competitors", _, "profile"] => "competitor_profile",
+            [.., "players", _, "profile"] => "player_profile",
+            [.., "competitions"] => "competitions",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+
+    /// Returns the path of the endpoint without the format extension, e.g.
+    /// `seasons/sr:season:105353/schedules`.
+    pub fn path(&self) -> String {
+        match self {
+            Endpoint::Competitions => "competitions".to_string(),
+            Endpoint::CompetitionInfo { competition_id } => {
+                format!("competitions/{}/info", competition_id)
+            }
+            Endpoint::CompetitionSeasons { competition_id } => {
+                format!("competitions/{}/seasons", competition_id)
+            }
+            Endpoint::SeasonInfo { season_id } => format!("seasons/{}/info", season_id),
+            Endpoint::SeasonCompetitors { season_id } => {
+                format!("seasons/{}/competitors", season_id)
+            }
+            Endpoint::SeasonSchedules { season_id, .. } => {
+                format!("seasons/{}/schedules", season_id)
+            }
+            Endpoint::SeasonCompetitorStatistics {
+                season_id,
+                competitor_id,
+            } => format!(
+                "seasons/{}/competitors/{}/statistics",
+                season_id, competitor_id
+            ),
+            Endpoint::CompetitorProfile { competitor_id } => {
+                format!("competitors/{}/profile", competitor_id)
+            }
+            Endpoint::PlayerProfile { player_id } => format!("players/{}/profile", player_id),
+        }
+    }
+
+    /// Returns the query parameters of the endpoint (without the API key).
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Endpoint::SeasonSchedules { start, limit, .. } => [("start", start), ("limit", limit)]
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value.as_ref()?.to_string())))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints() -> Vec<Endpoint> {
+        vec![
+            Endpoint::Competitions,
+            Endpoint::CompetitionInfo {
+                competition_id: "sr:competition:17".to_string(),
+            },
+            Endpoint::CompetitionSeasons {
+                competition_id: "sr:competition:17".to_string(),
+            },
+            Endpoint::SeasonInfo {
+                season_id: "sr:season:105353".to_string(),
+            },
+            Endpoint::SeasonCompetitors {
+                season_id: "sr:season:105353".to_string(),
+            },
+            Endpoint::SeasonSchedules {
+                season_id: "sr:season:105353".to_string(),
+                start: None,
+                limit: None,
+            },
+            Endpoint::SeasonCompetitorStatistics {
+                season_id: "sr:season:105353".to_string(),
+                competitor_id: "sr:competitor:42".to_string(),
+            },
+            Endpoint::CompetitorProfile {
+                competitor_id: "sr:competitor:42".to_string(),
+            },
+            Endpoint::PlayerProfile {
+                player_id: "sr:player:159665".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn path_of_every_endpoint() {
+        let paths: Vec<String> = endpoints().iter().map(Endpoint::path).collect();
+
+        assert_eq!(
+            paths,
+            [
+                "competitions",
+                "competitions/sr:competition:17/info",
+                "competitions/sr:competition:17/seasons",
+                "seasons/sr:season:105353/info",
+                "seasons/sr:season:105353/competitors",
+                "seasons/sr:season:105353/schedules",
+                "seasons/sr:season:105353/competitors/sr:competitor:42/statistics",
+                "competitors/sr:competitor:42/profile",
+                "players/sr:player:159665/profile",
+            ]
+        );
+    }
+
+    #[test]
+    fn query_of_season_schedules_pages() {
+        let schedules = |start, limit| Endpoint::SeasonSchedules {
+            season_id: "sr:season:105353".to_string(),
+            start,
+            limit,
+        };
+
+        assert_eq!(schedules(None, None).query(), []);
+        assert_eq!(
+            schedules(Some(200), Some(100)).query(),
+            [("start", "200".to_string()), ("limit", "100".to_string())]
+        );
+        assert_eq!(
+            schedules(None, Some(100)).query(),
+            [("limit", "100".to_string())]
+        );
+    }
+
+    #[test]
+    fn query_of_other_endpoints_is_empty() {
+        for endpoint in endpoints() {
+            if !matches!(endpoint, Endpoint::SeasonSchedules { .. }) {
+                assert_eq!(endpoint.query(), [], "{}", endpoint);
+            }
+        }
+    }
+
+    #[test]
+    fn names_are_unique_and_in_variant_order() {
+        let names: Vec<&str> = endpoints().iter().map(Endpoint::name).collect();
+
+        assert_eq!(names, Endpoint::names());
+        let mut unique = names.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), names.len());
+    }
+
+    #[test]
+    fn name_of_path_matches_every_endpoint() {
+        for endpoint in endpoints() {
+            let path = format!("/soccer/trial/v4/en/{}.json", endpoint.path());
+            assert_eq!(
+                Endpoint::name_of_path(&path),
+                Some(endpoint.name()),
+                "{}",
+                path
+            );
+            assert_eq!(
+                Endpoint::name_of_path(&endpoint.path()),
+                Some(endpoint.name())
+            );
+        }
+    }
+
+    #[test]
+    fn name_of_unknown_path_is_none() {
+        assert_eq!(
+            Endpoint::name_of_path(
+                "/soccer/trial/v4/en/sport_events/sr:sport_event:1/summary.json"
+            ),
+            None
+        );
+        assert_eq!(Endpoint::name_of_path(""), None);
+    }
+}
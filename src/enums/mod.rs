@@ -1,3 +1,11 @@
+mod endpoint_enums;
+mod navigation_enums;
 mod player_enums;
+mod response_format_enums;
+mod search_enums;
 
+pub use endpoint_enums::*;
+pub use navigation_enums::*;
 pub use player_enums::*;
+pub use response_format_enums::*;
+pub use search_enums::*;
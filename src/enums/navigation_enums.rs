@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use super::Players;
+
+/// The steps of the interactive wizard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Sport,
+    Competition,
+    Season,
+    PlayerStatistics,
+    Limit,
+    Results,
+    NextAction,
+}
+
+/// A choice made at a wizard step: either one of the offered options or going back to the
+/// previous step.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Navigation<T> {
+    Selected(T),
+    Back,
+}
+
+impl<T: Display> Display for Navigation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Navigation::Selected(option) => write!(f, "{}", option),
+            Navigation::Back => write!(f, "← Back"),
+        }
+    }
+}
+
+/// The options of the statistics step of the wizard: the leaderboard of a metric, or the search of
+/// the players and teams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuAction {
+    Leaderboard(Players),
+    Search,
+}
+
+impl Display for MenuAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuAction::Leaderboard(metric) => write!(f, "{}", metric),
+            MenuAction::Search => write!(f, "Search Players and Teams"),
+        }
+    }
+}
+
+/// The actions offered to the user after the results are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NextAction {
+    ChangePlayerStatistics,
+    ChangeLimit,
+    ChangeSeason,
+    ChangeCompetition,
+    ChangeSport,
+    Quit,
+}
+
+impl NextAction {
+    /// Returns the wizard step the action leads to (`None` for quitting).
+    pub fn step(&self) -> Option<Step> {
+        match self {
+            NextAction::ChangePlayerStatistics => Some(Step::PlayerStatistics),
+            NextAction::ChangeLimit => Some(Step::Limit),
+            NextAction::ChangeSeason => Some(Step::Season),
+            NextAction::ChangeCompetition => Some(Step::Competition),
+            NextAction::ChangeSport => Some(Step::Sport),
+            NextAction::Quit => None,
+        }
+    }
+}
+
+impl Display for NextAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NextAction::ChangePlayerStatistics => write!(f, "Change statistics"),
+            NextAction::ChangeLimit => write!(f, "Change the number of players"),
+            NextAction::ChangeSeason => write!(f, "Change season"),
+            NextAction::ChangeCompetition => write!(f, "Change competition"),
+            NextAction::ChangeSport => write!(f, "Change sport"),
+            NextAction::Quit => write!(f, "Quit"),
+        }
+    }
+}
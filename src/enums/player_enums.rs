@@ -1,4 +1,6 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use crate::services::PlayerSeasonStatisticsService;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Players {
@@ -6,6 +8,36 @@ pub enum Players {
     TopAssistants,
 }
 
+impl Players {
+    /// The statistics the players are ranked by, e.g. in the leaderboards of the reports.
+    pub const METRICS: [Players; 2] = [Players::TopGoalScorers, Players::TopAssistants];
+
+    /// Returns the name of the metric used in the command line and the HTTP API (`goals` or
+    /// `assists`).
+    pub fn metric_name(&self) -> &'static str {
+        match self {
+            Players::TopGoalScorers => "goals",
+            Players::TopAssistants => "assists",
+        }
+    }
+
+    /// Returns the title of the column of the metric's values, e.g. `Goals`.
+    pub fn column_title(&self) -> &'static str {
+        match self {
+            Players::TopGoalScorers => "Goals",
+            Players::TopAssistants => "Assists",
+        }
+    }
+
+    /// Returns the player's season value of the metric.
+    pub fn season_value<P: PlayerSeasonStatisticsService>(&self, player: &P) -> u8 {
+        match self {
+            Players::TopGoalScorers => player.season_goals(),
+            Players::TopAssistants => player.season_assists(),
+        }
+    }
+}
+
 impl Display for Players {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -14,3 +46,19 @@ impl Display for Players {
         }
     }
 }
+
+/// Parses the metric name used in the command line and the HTTP API ("goals" or "assists").
+impl FromStr for Players {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "goals" => Ok(Players::TopGoalScorers),
+            "assists" => Ok(Players::TopAssistants),
+            _ => Err(format!(
+                "Unknown metric '{}', expected 'goals' or 'assists'",
+                s
+            )),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    helpers::{deserialize_json_with_diagnostics, deserialize_xml_with_diagnostics},
+    models::ResponseDiagnostics,
+};
+
+/// The format of the Sportradar API responses, set by the `SPORT_RADAR_API_FORMAT` environment
+/// variable. The format is also the extension of the endpoint URLs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    Xml,
+}
+
+impl ResponseFormat {
+    /// Deserializes the response body with the deserializer of the format.
+    pub fn deserialize<Response: DeserializeOwned>(
+        &self,
+        body: &str,
+    ) -> Result<Response, Box<dyn Error>> {
+        self.deserialize_with_diagnostics(body)
+            .map(|(response, _)| response)
+    }
+
+    /// Deserializes the response body with the deserializer of the format and reports the
+    /// differences between the response and the models.
+    pub fn deserialize_with_diagnostics<Response: DeserializeOwned>(
+        &self,
+        body: &str,
+    ) -> Result<(Response, ResponseDiagnostics), Box<dyn Error>> {
+        match self {
+            ResponseFormat::Json => deserialize_json_with_diagnostics(body),
+            ResponseFormat::Xml => deserialize_xml_with_diagnostics(body),
+        }
+    }
+}
+
+impl Display for ResponseFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResponseFormat::Json => write!(f, "json"),
+            ResponseFormat::Xml => write!(f, "xml"),
+        }
+    }
+}
+
+impl FromStr for ResponseFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ResponseFormat::Json),
+            "xml" => Ok(ResponseFormat::Xml),
+            _ => Err(format!(
+                "Unknown response format '{}', expected 'json' or 'xml'",
+                s
+            )),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use crate::services::BaseInfoService;
+
+/// A single result of searching players and teams by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SearchHit<Player, Team> {
+    Player(Player),
+    Team(Team),
+}
+
+impl<Player: BaseInfoService, Team: BaseInfoService> SearchHit<Player, Team> {
+    /// Returns the name of the player or the team, the text the search matches.
+    pub fn name(&self) -> String {
+        match self {
+            SearchHit::Player(player) => player.name(),
+            SearchHit::Team(team) => team.name(),
+        }
+    }
+}
+
+impl<Player: Display, Team: Display> Display for SearchHit<Player, Team> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchHit::Player(player) => write!(f, "Player: {}", player),
+            SearchHit::Team(team) => write!(f, "Team: {}", team),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{helpers::match_score, models::Team};
+
+    fn team(name: &str) -> Team {
+        Team {
+            id: format!("sr:competitor:{}", name.len()),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn hit_name_leaves_out_the_kind() {
+        let player: SearchHit<Team, Team> = SearchHit::Player(team("Haaland, Erling"));
+        let club: SearchHit<Team, Team> = SearchHit::Team(team("Manchester City"));
+
+        assert_eq!(player.to_string(), "Player: Haaland, Erling");
+        assert_eq!(player.name(), "Haaland, Erling");
+        assert_eq!(club.name(), "Manchester City");
+        // The kind shown in the list is not searched
+        assert_eq!(match_score("pla", &player.name()), None);
+        assert_eq!(match_score("team", &club.name()), None);
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow::{
+    array::{ArrayRef, RecordBatch, StringArray, TimestampMillisecondArray, UInt32Array},
+    datatypes::{DataType, Field, Schema, TimeUnit},
+};
+use parquet::{
+    arrow::ArrowWriter,
+    file::{metadata::KeyValue, properties::WriterProperties},
+};
+use sportradar::{
+    helpers::file_safe_id,
+    services::{BaseInfoService, PlayerService},
+};
+
+use super::{team_table::team_table, ExportedSeason};
+
+/// The version of the columnar schema, bumped on every incompatible change of the columns.
+const SCHEMA_VERSION: &str = "1";
+
+/// The columnar file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnarFormat {
+    Parquet,
+    ArrowIpc,
+    Csv,
+}
+
+impl ColumnarFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ColumnarFormat::Parquet => "parquet",
+            ColumnarFormat::ArrowIpc => "arrow",
+            ColumnarFormat::Csv => "csv",
+        }
+    }
+}
+
+// The schema of the player season statistics, one row per player
+fn players_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("competition_id", DataType::Utf8, false),
+        Field::new("season_id", DataType::Utf8, false),
+        Field::new("player_id", DataType::Utf8, false),
+        Field::new("player_name", DataType::Utf8, false),
+        Field::new("team_id", DataType::Utf8, true),
+        Field::new("team_name", DataType::Utf8, true),
+        Field::new("goals", DataType::UInt32, false),
+        Field::new("assists", DataType::UInt32, false),
+    ])
+}
+
+// The schema of the team season statistics, one row per team
+fn teams_schema() -> Schema {
+    let kickoff = DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into()));
+    Schema::new(vec![
+        Field::new("competition_id", DataType::Utf8, false),
+        Field::new("season_id", DataType::Utf8, false),
+        Field::new("team_id", DataType::Utf8, false),
+        Field::new("team_name", DataType::Utf8, false),
+        Field::new("matches", DataType::UInt32, false),
+        Field::new("players", DataType::UInt32, false),
+        Field::new("goals", DataType::UInt32, false),
+        Field::new("assists", DataType::UInt32, false),
+        Field::new("first_kickoff", kickoff.clone(), true),
+        Field::new("last_kickoff", kickoff, true),
+    ])
+}
+
+// The file metadata noting the competition, the season and when it was fetched
+fn season_metadata(season: &ExportedSeason) -> HashMap<String, String> {
+    let (competition, selected_season) = (&season.data.competition, &season.data.season);
+
+    [
+        (
+            "sportradar.schema_version",
+            Some(SCHEMA_VERSION.to_string()),
+        ),
+        ("sportradar.competition_id", Some(competition.id())),
+        ("sportradar.competition_name", Some(competition.name())),
+        ("sportradar.season_id", Some(selected_season.id())),
+        ("sportradar.season_name", Some(selected_season.name())),
+        ("sportradar.fetched_at", season.data.fetched_at.clone()),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| Some((key.to_string(), value?)))
+    .collect()
+}
+
+// Get the ids of the competition and the season
+fn season_ids(season: &ExportedSeason) -> (String, String) {
+    (season.data.competition.id(), season.data.season.id())
+}
+
+fn players_batch(season: &ExportedSeason) -> Result<RecordBatch, Box<dyn Error>> {
+    let (competition_id, season_id) = season_ids(season);
+    let mut players: Vec<_> = season.data.players.iter().collect();
+    players.sort_by_key(|player| player.id());
+
+    // The team is unknown (empty) for the players restored from the snapshots without it
+    let team = |value: String| Some(value).filter(|value| !value.is_empty());
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(vec![competition_id; players.len()])),
+        Arc::new(StringArray::from(vec![season_id; players.len()])),
+        Arc::new(StringArray::from_iter_values(
+            players.iter().map(|p| p.id()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            players.iter().map(|p| p.name()),
+        )),
+        Arc::new(StringArray::from_iter(
+            players.iter().map(|p| team(p.team().id())),
+        )),
+        Arc::new(StringArray::from_iter(
+            players.iter().map(|p| team(p.team().name())),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            players
+                .iter()
+                .map(|p| u32::from(p.season_statistics.goals_scored)),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            players
+                .iter()
+                .map(|p| u32::from(p.season_statistics.assists)),
+        )),
+    ];
+
+    let schema = players_schema().with_metadata(season_metadata(season));
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+}
+
+fn teams_batch(season: &ExportedSeason) -> Result<RecordBatch, Box<dyn Error>> {
+    let (competition_id, season_id) = season_ids(season);
+    let teams = team_table(&season.data);
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(vec![competition_id; teams.len()])),
+        Arc::new(StringArray::from(vec![season_id; teams.len()])),
+        Arc::new(StringArray::from_iter_values(
+            teams.iter().map(|row| &row.id),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            teams.iter().map(|row| &row.name),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            teams.iter().map(|row| row.matches),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            teams.iter().map(|row| row.players),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            teams.iter().map(|row| row.goals),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            teams.iter().map(|row| row.assists),
+        )),
+        Arc::new(
+            TimestampMillisecondArray::from_iter(teams.iter().map(|row| row.first_kickoff))
+                .with_timezone("+00:00"),
+        ),
+        Arc::new(
+            TimestampMillisecondArray::from_iter(teams.iter().map(|row| row.last_kickoff))
+                .with_timezone("+00:00"),
+        ),
+    ];
+
+    let schema = teams_schema().with_metadata(season_metadata(season));
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+}
+
+fn write_batch(
+    path: &Path,
+    format: ColumnarFormat,
+    batch: &RecordBatch,
+) -> Result<(), Box<dyn Error>> {
+    let file = File::create(path)?;
+    match format {
+        ColumnarFormat::Parquet => {
+            // The metadata is also written as the Parquet key-value metadata, so the tools
+            // reading the Parquet files without the Arrow schema see it too
+            let metadata = batch
+                .schema()
+                .metadata()
+                .iter()
+                .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+                .collect();
+            let properties = WriterProperties::builder()
+                .set_key_value_metadata(Some(metadata))
+                .build();
+            let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(properties))?;
+            writer.write(batch)?;
+            writer.close()?;
+        }
+        ColumnarFormat::ArrowIpc => {
+            let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &batch.schema())?;
+            writer.write(batch)?;
+            writer.finish()?;
+        }
+        // CSV has no place for the metadata, the files are named after the season instead
+        ColumnarFormat::Csv => {
+            let mut writer = arrow::csv::Writer::new(file);
+            writer.write(batch)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the player and the team season statistics of the season into the directory, as
+/// `<season>_players.<ext>` and `<season>_teams.<ext>` files (see the schemas in the README).
+///
+/// # Returns
+/// The paths of the written files.
+pub fn export_columnar(
+    dir: &Path,
+    format: ColumnarFormat,
+    season: &ExportedSeason,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let (_, season_id) = season_ids(season);
+    let prefix = file_safe_id(&season_id);
+
+    [
+        ("players", players_batch(season)?),
+        ("teams", teams_batch(season)?),
+    ]
+    .into_iter()
+    .map(|(name, batch)| {
+        let path = dir.join(format!("{}_{}.{}", prefix, name, format.extension()));
+        write_batch(&path, format, &batch)?;
+        Ok(path)
+    })
+    .collect()
+}
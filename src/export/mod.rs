@@ -0,0 +1,93 @@
+mod columnar_export;
+mod sqlite_export;
+mod team_table;
+mod xlsx_export;
+
+use std::error::Error;
+
+use sportradar::{
+    enums::Players,
+    helpers::{get_cache_dir, load_cached_seasons, top_players},
+    models::{Player, SeasonData},
+};
+
+use crate::commands::ExportArgs;
+
+pub use self::team_table::{kickoff_millis, league_table};
+
+use self::columnar_export::ColumnarFormat;
+
+/// A season from the local cache with the number of the players on its leaderboards. The exports
+/// and the reports read the season's models directly, they never call the API.
+pub struct ExportedSeason {
+    pub data: SeasonData,
+    pub limit: usize,
+}
+
+impl ExportedSeason {
+    pub fn new(data: SeasonData, limit: usize) -> Self {
+        Self { data, limit }
+    }
+
+    /// Returns the `limit` best players of the season by the statistics, ranked the same way as in
+    /// the interactive mode (see [`top_players`]).
+    pub fn top_players(&self, statistics: Players) -> Vec<Player> {
+        top_players(&self.data.players, statistics, self.limit)
+    }
+}
+
+/// Exports the seasons from the local cache (the ones loaded in the interactive mode, see
+/// [`sportradar::services::AppStateService::on_season_select`]) into the formats chosen by the
+/// command options.
+pub fn run_export(args: &ExportArgs) -> Result<(), Box<dyn Error>> {
+    let columnar_dirs = [
+        (ColumnarFormat::Parquet, &args.parquet),
+        (ColumnarFormat::ArrowIpc, &args.arrow),
+        (ColumnarFormat::Csv, &args.csv),
+    ];
+    if args.sqlite.is_none()
+        && args.xlsx.is_none()
+        && columnar_dirs.iter().all(|(_, dir)| dir.is_none())
+    {
+        return Err(
+            "Choose the export format: --sqlite <path>, --xlsx <dir>, --parquet <dir>, --arrow <dir>, or --csv <dir>"
+                .into(),
+        );
+    }
+
+    let seasons: Vec<ExportedSeason> =
+        load_cached_seasons(&get_cache_dir(), args.season.as_deref(), args.all_seasons)?
+            .into_iter()
+            .map(|snapshot| ExportedSeason::new(snapshot, args.limit))
+            .collect();
+
+    if let Some(sqlite_path) = &args.sqlite {
+        let snapshots: Vec<_> = seasons.iter().map(|season| &season.data).collect();
+        sqlite_export::export_to_sqlite(sqlite_path, &snapshots)?;
+        println!(
+            "Exported {} seasons to {}",
+            seasons.len(),
+            sqlite_path.display()
+        );
+    }
+
+    if let Some(dir) = &args.xlsx {
+        for season in &seasons {
+            let path = xlsx_export::export_to_xlsx(dir, season)?;
+            println!("Exported {}", path.display());
+        }
+    }
+
+    for (format, dir) in columnar_dirs {
+        let Some(dir) = dir else {
+            continue;
+        };
+        for season in &seasons {
+            for path in columnar_export::export_columnar(dir, format, season)? {
+                println!("Exported {}", path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
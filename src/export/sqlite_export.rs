@@ -0,0 +1,260 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection, Transaction};
+use sportradar::{
+    models::SeasonData,
+    services::{BaseInfoService, CompetitorsService, PlayerService},
+};
+
+// The normalized schema of the exported data. The statistics of a player are kept per season and
+// per team, so the seasons exported one after another build up a history.
+const SCHEMA: &str = "
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS countries (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS competitions (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    country_id TEXT NOT NULL REFERENCES countries (id)
+);
+
+CREATE TABLE IF NOT EXISTS seasons (
+    id TEXT PRIMARY KEY,
+    competition_id TEXT NOT NULL REFERENCES competitions (id),
+    name TEXT NOT NULL,
+    disabled INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS competitors (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS season_competitors (
+    season_id TEXT NOT NULL REFERENCES seasons (id),
+    competitor_id TEXT NOT NULL REFERENCES competitors (id),
+    PRIMARY KEY (season_id, competitor_id)
+);
+
+CREATE TABLE IF NOT EXISTS sport_events (
+    id TEXT PRIMARY KEY,
+    season_id TEXT NOT NULL REFERENCES seasons (id),
+    -- The scheduled kickoff time in the RFC 3339 format, NULL if the API omits it
+    start_time TEXT
+);
+
+CREATE TABLE IF NOT EXISTS sport_event_competitors (
+    sport_event_id TEXT NOT NULL REFERENCES sport_events (id),
+    competitor_id TEXT NOT NULL REFERENCES competitors (id),
+    PRIMARY KEY (sport_event_id, competitor_id)
+);
+
+CREATE TABLE IF NOT EXISTS players (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS player_season_statistics (
+    player_id TEXT NOT NULL REFERENCES players (id),
+    season_id TEXT NOT NULL REFERENCES seasons (id),
+    competitor_id TEXT NOT NULL REFERENCES competitors (id),
+    goals INTEGER NOT NULL,
+    assists INTEGER NOT NULL,
+    PRIMARY KEY (player_id, season_id, competitor_id)
+);
+";
+
+// The columns added to the tables after their first version, added to the databases exported
+// before them
+const ADDED_COLUMNS: [(&str, &str, &str); 1] = [("sport_events", "start_time", "TEXT")];
+
+// Add the columns missing in a database exported with an older schema
+fn add_missing_columns(connection: &Connection) -> rusqlite::Result<()> {
+    for (table, column, column_type) in ADDED_COLUMNS {
+        let exists: bool = connection.query_row(
+            "SELECT EXISTS (SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+            params![table, column],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            connection.execute_batch(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                table, column, column_type
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the seasons into the SQLite database at the path, creating the database and its schema
+/// if needed. The rows exported before are updated, so exporting the seasons one after another
+/// builds up a historical archive.
+///
+/// The competitors and the players without an id are skipped, so are the statistics of the
+/// players whose team is unknown (in the snapshots saved before the team was recorded).
+pub fn export_to_sqlite(path: &Path, seasons: &[&SeasonData]) -> rusqlite::Result<()> {
+    let mut connection = Connection::open(path)?;
+    connection.execute_batch(SCHEMA)?;
+    add_missing_columns(&connection)?;
+
+    let transaction = connection.transaction()?;
+    for season in seasons {
+        export_season(&transaction, season)?;
+    }
+
+    transaction.commit()
+}
+
+// Upsert all the entities of the season
+fn export_season(transaction: &Transaction, data: &SeasonData) -> rusqlite::Result<()> {
+    let (competition, season) = (&data.competition, &data.season);
+
+    transaction.execute(
+        "INSERT INTO countries (id, name) VALUES (?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET name = excluded.name",
+        params![competition.category.id, competition.category.name],
+    )?;
+    transaction.execute(
+        "INSERT INTO competitions (id, name, country_id) VALUES (?1, ?2, ?3)
+         ON CONFLICT (id) DO UPDATE SET name = excluded.name, country_id = excluded.country_id",
+        params![competition.id, competition.name, competition.category.id],
+    )?;
+    transaction.execute(
+        "INSERT INTO seasons (id, competition_id, name, disabled) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (id) DO UPDATE SET
+             competition_id = excluded.competition_id,
+             name = excluded.name,
+             disabled = excluded.disabled",
+        params![season.id, competition.id, season.name, season.disabled],
+    )?;
+
+    let mut insert_competitor = transaction.prepare(
+        "INSERT INTO competitors (id, name) VALUES (?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET name = excluded.name",
+    )?;
+    let mut insert_season_competitor = transaction.prepare(
+        "INSERT INTO season_competitors (season_id, competitor_id) VALUES (?1, ?2)
+         ON CONFLICT DO NOTHING",
+    )?;
+    for competitor in data.competitors.iter().filter(|team| !team.id.is_empty()) {
+        insert_competitor.execute(params![competitor.id(), competitor.name()])?;
+        insert_season_competitor.execute(params![season.id, competitor.id()])?;
+    }
+
+    let mut insert_sport_event = transaction.prepare(
+        "INSERT INTO sport_events (id, season_id, start_time) VALUES (?1, ?2, ?3)
+         ON CONFLICT (id) DO UPDATE SET
+             season_id = excluded.season_id,
+             start_time = excluded.start_time",
+    )?;
+    let mut insert_sport_event_competitor = transaction.prepare(
+        "INSERT INTO sport_event_competitors (sport_event_id, competitor_id) VALUES (?1, ?2)
+         ON CONFLICT DO NOTHING",
+    )?;
+    for sport_event in &data.sport_events {
+        insert_sport_event.execute(params![sport_event.id, season.id, sport_event.start_time])?;
+        for competitor in sport_event.competitors() {
+            if competitor.id.is_empty() {
+                continue;
+            }
+            // The competitors of the sport events are the competitors of the season, but they are
+            // upserted too in case the schedules list a team without the statistics
+            insert_competitor.execute(params![competitor.id(), competitor.name()])?;
+            insert_sport_event_competitor.execute(params![sport_event.id, competitor.id()])?;
+        }
+    }
+
+    let mut insert_player = transaction.prepare(
+        "INSERT INTO players (id, name) VALUES (?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET name = excluded.name",
+    )?;
+    let mut insert_statistics = transaction.prepare(
+        "INSERT INTO player_season_statistics (player_id, season_id, competitor_id, goals, assists)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT (player_id, season_id, competitor_id) DO UPDATE SET
+             goals = excluded.goals,
+             assists = excluded.assists",
+    )?;
+    for player in data.players.iter().filter(|player| !player.id().is_empty()) {
+        insert_player.execute(params![player.id(), player.name()])?;
+        let team = player.team();
+        if team.id.is_empty() {
+            tracing::warn!(player = %player.id(), "Skipped the statistics of a player without a team");
+            continue;
+        }
+        insert_competitor.execute(params![team.id(), team.name()])?;
+        insert_statistics.execute(params![
+            player.id(),
+            season.id,
+            team.id(),
+            player.season_statistics.goals_scored,
+            player.season_statistics.assists
+        ])?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn export_keeps_kickoff_and_skips_empty_ids() {
+        let season: SeasonData = serde_json::from_value(json!({
+            "competition": {
+                "id": "sr:competition:17",
+                "name": "Premier League",
+                "category": { "id": "sr:category:1", "name": "England" },
+            },
+            "season": { "id": "sr:season:1", "name": "Premier League 23/24" },
+            "sport_events": [{
+                "id": "sr:sport_event:1",
+                "start_time": "2023-08-11T19:00:00+00:00",
+                "competitors": [
+                    { "id": "sr:competitor:17", "name": "Manchester City" },
+                    { "id": "", "name": "" },
+                ],
+            }],
+            "competitors": [{ "id": "", "name": "" }],
+            "players": [
+                { "id": "sr:player:1", "name": "Haaland, Erling", "team": { "id": "", "name": "" } },
+            ],
+        }))
+        .unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("sportradar_export_{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        // A database exported before the kickoff time was kept
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE sport_events (id TEXT PRIMARY KEY, season_id TEXT NOT NULL REFERENCES seasons (id));",
+            )
+            .unwrap();
+
+        export_to_sqlite(&path, &[&season]).unwrap();
+
+        let connection = Connection::open(&path).unwrap();
+        let count = |sql: &str| -> i64 { connection.query_row(sql, [], |row| row.get(0)).unwrap() };
+        let start_time: String = connection
+            .query_row("SELECT start_time FROM sport_events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(start_time, "2023-08-11T19:00:00+00:00");
+        assert_eq!(count("SELECT COUNT(*) FROM competitors WHERE id = ''"), 0);
+        assert_eq!(count("SELECT COUNT(*) FROM sport_event_competitors"), 1);
+        assert_eq!(count("SELECT COUNT(*) FROM players"), 1);
+        assert_eq!(count("SELECT COUNT(*) FROM player_season_statistics"), 0);
+
+        drop(connection);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+};
+
+use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use sportradar::{
+    models::{SeasonData, SportEvent},
+    services::{BaseInfoService, CompetitorsService, PlayerService, SportEventStatusService},
+};
+
+/// The season statistics of a team aggregated from its players and sport events.
+#[derive(Debug, Default)]
+pub struct TeamRow {
+    pub id: String,
+    pub name: String,
+    /// The number of the sport events of the season the team plays in.
+    pub matches: u32,
+    /// The results of the closed sport events of the team, see [`league_table`].
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub goals_for: u32,
+    pub goals_against: u32,
+    pub players: u32,
+    /// The season goals of the team's players.
+    pub goals: u32,
+    /// The season assists of the team's players.
+    pub assists: u32,
+    /// The first kickoff time of the team in the season, in milliseconds since the Unix epoch.
+    pub first_kickoff: Option<i64>,
+    /// The last kickoff time of the team in the season, in milliseconds since the Unix epoch.
+    pub last_kickoff: Option<i64>,
+}
+
+impl TeamRow {
+    /// The number of the closed sport events of the team.
+    pub fn played(&self) -> u32 {
+        self.won + self.drawn + self.lost
+    }
+
+    /// The league points of the team, 3 for a win and 1 for a draw.
+    pub fn points(&self) -> u32 {
+        3 * self.won + self.drawn
+    }
+
+    pub fn goal_difference(&self) -> i64 {
+        i64::from(self.goals_for) - i64::from(self.goals_against)
+    }
+}
+
+/// Returns the kickoff time of the sport event in milliseconds since the Unix epoch. The kickoff
+/// times the API omits or which cannot be parsed are `None`.
+pub fn kickoff_millis(sport_event: &SportEvent) -> Option<i64> {
+    sport_event
+        .start_time
+        .as_deref()
+        .and_then(|start_time| string_to_timestamp_nanos(start_time).ok())
+        .map(|nanos| nanos / 1_000_000)
+}
+
+/// Aggregates the season statistics of every team of the season (the competitors, and the teams of
+/// the sport events and the players), sorted by the team id.
+pub fn team_table(season: &SeasonData) -> Vec<TeamRow> {
+    let mut teams: HashMap<String, TeamRow> = HashMap::new();
+    for team in &season.competitors {
+        team_row(&mut teams, team.id(), team.name());
+    }
+
+    for sport_event in &season.sport_events {
+        let kickoff = kickoff_millis(sport_event);
+        for competitor in sport_event.competitors() {
+            let row = team_row(&mut teams, competitor.id(), competitor.name());
+            row.matches += 1;
+            if let Some(kickoff) = kickoff {
+                row.first_kickoff = Some(row.first_kickoff.map_or(kickoff, |k| k.min(kickoff)));
+                row.last_kickoff = Some(row.last_kickoff.map_or(kickoff, |k| k.max(kickoff)));
+            }
+        }
+
+        // The API lists the home competitor first
+        let score = sport_event.status().and_then(|status| status.final_score());
+        if let (Some((home_score, away_score)), [home, away]) =
+            (score, sport_event.competitors().as_slice())
+        {
+            add_result(&mut teams, home, home_score, away_score);
+            add_result(&mut teams, away, away_score, home_score);
+        }
+    }
+
+    for player in &season.players {
+        let team = player.team();
+        // The team is unknown (empty) for the players restored from the snapshots without it
+        if team.id().is_empty() {
+            continue;
+        }
+        let row = team_row(&mut teams, team.id(), team.name());
+        row.players += 1;
+        row.goals += u32::from(player.season_statistics.goals_scored);
+        row.assists += u32::from(player.season_statistics.assists);
+    }
+
+    let mut teams: Vec<TeamRow> = teams.into_values().collect();
+    teams.sort_by(|a, b| a.id.cmp(&b.id));
+    teams
+}
+
+/// Ranks the teams of the [`team_table`] by the results of the closed sport events: by the points,
+/// then the goal difference, then the goals scored, then the name and the id.
+pub fn league_table(season: &SeasonData) -> Vec<TeamRow> {
+    let mut teams = team_table(season);
+    teams.sort_by(|a, b| {
+        let key = |team: &TeamRow| {
+            (
+                Reverse(team.points()),
+                Reverse(team.goal_difference()),
+                Reverse(team.goals_for),
+            )
+        };
+        key(a)
+            .cmp(&key(b))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    teams
+}
+
+// Add the result of a closed sport event to the row of the team
+fn add_result(
+    teams: &mut HashMap<String, TeamRow>,
+    team: &impl BaseInfoService,
+    scored: u8,
+    conceded: u8,
+) {
+    let row = team_row(teams, team.id(), team.name());
+    match scored.cmp(&conceded) {
+        Ordering::Greater => row.won += 1,
+        Ordering::Equal => row.drawn += 1,
+        Ordering::Less => row.lost += 1,
+    }
+    row.goals_for += u32::from(scored);
+    row.goals_against += u32::from(conceded);
+}
+
+// Get the row of the team, adding an empty one if the team is not in the table yet
+fn team_row(teams: &mut HashMap<String, TeamRow>, id: String, name: String) -> &mut TeamRow {
+    teams.entry(id.clone()).or_insert_with(|| TeamRow {
+        id,
+        name,
+        ..TeamRow::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn sport_event(id: &str, home: &str, away: &str, status: serde_json::Value) -> SportEvent {
+        let mut sport_event: SportEvent = serde_json::from_value(json!({
+            "id": id,
+            "competitors": [
+                { "id": format!("sr:competitor:{}", home), "name": home },
+                { "id": format!("sr:competitor:{}", away), "name": away },
+            ],
+        }))
+        .unwrap();
+        sport_event.set_status(serde_json::from_value(status).unwrap());
+        sport_event
+    }
+
+    #[test]
+    fn league_table_ranks_by_points_then_goal_difference() {
+        let closed = |home: u8, away: u8| json!({ "status": "closed", "home_score": home, "away_score": away });
+        let mut season: SeasonData = serde_json::from_value(json!({
+            "competition": {
+                "id": "sr:competition:17",
+                "name": "Premier League",
+                "category": { "id": "sr:category:1", "name": "England" },
+            },
+            "season": { "id": "sr:season:1", "name": "Premier League 23/24" },
+            "sport_events": [],
+            "competitors": [],
+            "players": [],
+        }))
+        .unwrap();
+        season.sport_events = vec![
+            sport_event("sr:sport_event:1", "Arsenal", "Chelsea", closed(2, 0)),
+            sport_event("sr:sport_event:2", "Chelsea", "Fulham", closed(1, 1)),
+            sport_event("sr:sport_event:3", "Fulham", "Arsenal", closed(3, 0)),
+            // Not counted until the result is confirmed
+            sport_event(
+                "sr:sport_event:4",
+                "Chelsea",
+                "Arsenal",
+                json!({ "status": "live", "home_score": 5, "away_score": 0 }),
+            ),
+        ];
+
+        let table = league_table(&season);
+        let summary: Vec<_> = table
+            .iter()
+            .map(|team| {
+                (
+                    team.name.as_str(),
+                    team.played(),
+                    team.points(),
+                    team.goal_difference(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Fulham", 2, 4, 3),
+                ("Arsenal", 2, 3, -1),
+                ("Chelsea", 2, 1, -2)
+            ]
+        );
+        assert_eq!(table[2].matches, 3);
+    }
+}
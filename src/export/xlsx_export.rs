@@ -0,0 +1,232 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use rust_xlsxwriter::{
+    Color, DocProperties, ExcelDateTime, Format, FormatBorder, Workbook, Worksheet, XlsxError,
+};
+use sportradar::{
+    enums::Players,
+    helpers::file_safe_id,
+    models::Player,
+    services::{BaseInfoService, CompetitorsService, PlayerSeasonStatisticsService, PlayerService},
+};
+
+use super::{
+    team_table::{kickoff_millis, league_table},
+    ExportedSeason,
+};
+
+// Write the header row of a sheet, freeze it, and add the autofilter over the header and the rows
+fn write_table_header(
+    sheet: &mut Worksheet,
+    headers: &[&str],
+    rows: usize,
+) -> Result<(), XlsxError> {
+    let header_format = Format::new()
+        .set_bold()
+        .set_font_color(Color::White)
+        .set_background_color(Color::RGB(0x1F4E78))
+        .set_border_bottom(FormatBorder::Thin);
+
+    sheet.write_row_with_format(0, 0, headers.iter().copied(), &header_format)?;
+    sheet.set_freeze_panes(1, 0)?;
+    sheet.autofilter(0, 0, rows as u32, headers.len() as u16 - 1)?;
+
+    Ok(())
+}
+
+// Write a leaderboard sheet, the players are already ranked
+fn write_leaderboard(
+    workbook: &mut Workbook,
+    name: &str,
+    unit: &str,
+    players: &[Player],
+    value: impl Fn(&Player) -> u8,
+) -> Result<(), XlsxError> {
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(name)?;
+    write_table_header(sheet, &["Rank", "Player", "Team", unit], players.len())?;
+
+    for (index, player) in players.iter().enumerate() {
+        let row = index as u32 + 1;
+        sheet.write(row, 0, row)?;
+        sheet.write(row, 1, player.name())?;
+        sheet.write(row, 2, player.team().name())?;
+        sheet.write(row, 3, value(player))?;
+    }
+    sheet.autofit();
+
+    Ok(())
+}
+
+fn write_league_table(workbook: &mut Workbook, season: &ExportedSeason) -> Result<(), XlsxError> {
+    let teams = league_table(&season.data);
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("League Table")?;
+    let headers = [
+        "Rank",
+        "Team",
+        "Played",
+        "Won",
+        "Drawn",
+        "Lost",
+        "Goals For",
+        "Goals Against",
+        "Goal Difference",
+        "Points",
+        "Player Goals",
+        "Player Assists",
+        "First Kickoff",
+        "Last Kickoff",
+    ];
+    write_table_header(sheet, &headers, teams.len())?;
+
+    let date_format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+    for (index, team) in teams.iter().enumerate() {
+        let row = index as u32 + 1;
+        sheet.write(row, 0, row)?;
+        sheet.write(row, 1, &team.name)?;
+        sheet.write(row, 2, team.played())?;
+        sheet.write(row, 3, team.won)?;
+        sheet.write(row, 4, team.drawn)?;
+        sheet.write(row, 5, team.lost)?;
+        sheet.write(row, 6, team.goals_for)?;
+        sheet.write(row, 7, team.goals_against)?;
+        sheet.write(row, 8, team.goal_difference())?;
+        sheet.write(row, 9, team.points())?;
+        sheet.write(row, 10, team.goals)?;
+        sheet.write(row, 11, team.assists)?;
+        for (column, kickoff) in [(12, team.first_kickoff), (13, team.last_kickoff)] {
+            if let Some(kickoff) = kickoff {
+                let kickoff = ExcelDateTime::from_timestamp(kickoff / 1000)?;
+                sheet.write_datetime_with_format(row, column, kickoff, &date_format)?;
+            }
+        }
+    }
+    sheet.autofit();
+
+    Ok(())
+}
+
+fn write_player_stats(workbook: &mut Workbook, season: &ExportedSeason) -> Result<(), XlsxError> {
+    let players: Vec<&Player> = season
+        .data
+        .players
+        .iter()
+        .sorted_by_key(|player| (player.team().name(), player.name()))
+        .collect();
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Player Stats")?;
+    write_table_header(
+        sheet,
+        &["Player", "Team", "Goals", "Assists", "Player Id", "Team Id"],
+        players.len(),
+    )?;
+
+    for (index, player) in players.iter().enumerate() {
+        let row = index as u32 + 1;
+        sheet.write(row, 0, player.name())?;
+        sheet.write(row, 1, player.team().name())?;
+        sheet.write(row, 2, player.season_goals())?;
+        sheet.write(row, 3, player.season_assists())?;
+        sheet.write(row, 4, player.id())?;
+        sheet.write(row, 5, player.team().id())?;
+    }
+    sheet.autofit();
+
+    Ok(())
+}
+
+fn write_fixtures(workbook: &mut Workbook, season: &ExportedSeason) -> Result<(), XlsxError> {
+    // The sport events without the kickoff time are listed last
+    let sport_events: Vec<_> = season
+        .data
+        .sport_events
+        .iter()
+        .map(|sport_event| (kickoff_millis(sport_event), sport_event))
+        .sorted_by_key(|(kickoff, sport_event)| {
+            (kickoff.is_none(), *kickoff, sport_event.id.clone())
+        })
+        .collect();
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Fixtures")?;
+    write_table_header(
+        sheet,
+        &["Kickoff", "Home", "Away", "Sport Event Id"],
+        sport_events.len(),
+    )?;
+
+    let date_format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+    for (index, (kickoff, sport_event)) in sport_events.iter().enumerate() {
+        let row = index as u32 + 1;
+        if let Some(kickoff) = kickoff {
+            let kickoff = ExcelDateTime::from_timestamp(kickoff / 1000)?;
+            sheet.write_datetime_with_format(row, 0, kickoff, &date_format)?;
+        }
+        // The API lists the home competitor first
+        let competitors = sport_event.competitors();
+        for (column, competitor) in competitors.iter().take(2).enumerate() {
+            sheet.write(row, column as u16 + 1, competitor.name())?;
+        }
+        sheet.write(row, 3, &sport_event.id)?;
+    }
+    sheet.autofit();
+
+    Ok(())
+}
+
+/// Writes the season into the `<season>.xlsx` workbook in the directory, with the sheets of the
+/// top scorers and assistants (see [`ExportedSeason::top_players`]), the league table
+/// (see [`league_table`]), the statistics of all the players, and the fixtures. The kickoff times
+/// are in UTC.
+///
+/// # Returns
+/// The path of the written workbook.
+pub fn export_to_xlsx(dir: &Path, season: &ExportedSeason) -> Result<PathBuf, Box<dyn Error>> {
+    let (competition, selected_season) = (&season.data.competition, &season.data.season);
+
+    let mut workbook = Workbook::new();
+    let mut properties = DocProperties::new()
+        .set_title(format!(
+            "{} - {}",
+            competition.name(),
+            selected_season.name()
+        ))
+        .set_custom_property("Competition Id", competition.id())
+        .set_custom_property("Season Id", selected_season.id());
+    if let Some(fetched_at) = &season.data.fetched_at {
+        properties = properties.set_custom_property("Fetched At", fetched_at.as_str());
+    }
+    workbook.set_properties(&properties);
+
+    write_leaderboard(
+        &mut workbook,
+        "Top Scorers",
+        "Goals",
+        &season.top_players(Players::TopGoalScorers),
+        |player| player.season_goals(),
+    )?;
+    write_leaderboard(
+        &mut workbook,
+        "Top Assistants",
+        "Assists",
+        &season.top_players(Players::TopAssistants),
+        |player| player.season_assists(),
+    )?;
+    write_league_table(&mut workbook, season)?;
+    write_player_stats(&mut workbook, season)?;
+    write_fixtures(&mut workbook, season)?;
+
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.xlsx", file_safe_id(&selected_season.id())));
+    workbook.save(&path)?;
+
+    Ok(path)
+}
@@ -0,0 +1,126 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::fs_helpers::write_atomically;
+
+const EMPTY_CACHE: &str = "The local cache is empty. Load a season in the interactive mode first";
+
+// Get the directory where the season snapshots are stored in the local cache directory
+fn seasons_cache_dir(cache_dir: &Path) -> PathBuf {
+    cache_dir.join("seasons")
+}
+
+/// Returns the id usable in a file name, e.g. `sr_season_105353` for `sr:season:105353`. The
+/// Sportradar ids contain colons, which are not allowed in file names on every platform.
+pub fn file_safe_id(id: &str) -> String {
+    id.replace(':', "_")
+}
+
+fn season_snapshot_path(cache_dir: &Path, season_id: &str) -> PathBuf {
+    seasons_cache_dir(cache_dir).join(format!("{}.json", file_safe_id(season_id)))
+}
+
+/// Saves the season snapshot to the local cache directory, overwriting the previous snapshot of the same
+/// season (if any). The file is replaced atomically, so a concurrent reader never sees a
+/// truncated snapshot.
+///
+/// # Returns
+/// The path of the saved snapshot file.
+pub fn save_season_snapshot<Snapshot: Serialize>(
+    cache_dir: &Path,
+    season_id: &str,
+    snapshot: &Snapshot,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let path = season_snapshot_path(cache_dir, season_id);
+    write_atomically(&path, &serde_json::to_vec(snapshot)?)?;
+
+    Ok(path)
+}
+
+/// Removes the snapshot of the season with the given id from the local cache directory (if any).
+pub fn remove_season_snapshot(cache_dir: &Path, season_id: &str) -> Result<(), Box<dyn Error>> {
+    match fs::remove_file(season_snapshot_path(cache_dir, season_id)) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Loads the snapshot of the season with the given id from the local cache directory.
+///
+/// # Returns
+/// `None` if the season is not cached or the cached file cannot be parsed.
+pub fn load_season_snapshot<Snapshot: DeserializeOwned>(
+    cache_dir: &Path,
+    season_id: &str,
+) -> Option<Snapshot> {
+    let data = fs::read(season_snapshot_path(cache_dir, season_id)).ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Loads all the season snapshots from the local cache directory, the most recently saved first.
+/// Files which cannot be parsed are skipped.
+pub fn load_season_snapshots<Snapshot: DeserializeOwned>(cache_dir: &Path) -> Vec<Snapshot> {
+    let Ok(entries) = fs::read_dir(seasons_cache_dir(cache_dir)) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    files.sort_by(|a, b| Ord::cmp(&b.0, &a.0));
+
+    files
+        .into_iter()
+        .filter_map(|(_, path)| serde_json::from_slice(&fs::read(path).ok()?).ok())
+        .collect()
+}
+
+/// Loads the snapshot of the season with the given id from the local cache directory, or the most
+/// recently saved snapshot if no id is given.
+///
+/// # Errors
+/// If the season is not cached, or the cache is empty.
+pub fn load_cached_season<Snapshot: DeserializeOwned>(
+    cache_dir: &Path,
+    season_id: Option<&str>,
+) -> Result<Snapshot, Box<dyn Error>> {
+    let snapshot = match season_id {
+        Some(season_id) => load_season_snapshot(cache_dir, season_id)
+            .ok_or_else(|| format!("Season {} is not in the local cache", season_id))?,
+        None => load_season_snapshots(cache_dir)
+            .into_iter()
+            .next()
+            .ok_or(EMPTY_CACHE)?,
+    };
+
+    Ok(snapshot)
+}
+
+/// Loads all the season snapshots from the local cache directory (the most recently saved first)
+/// if `all_seasons` is set, otherwise the one chosen by [`load_cached_season`].
+///
+/// # Errors
+/// If the chosen season is not cached, or the cache is empty.
+pub fn load_cached_seasons<Snapshot: DeserializeOwned>(
+    cache_dir: &Path,
+    season_id: Option<&str>,
+    all_seasons: bool,
+) -> Result<Vec<Snapshot>, Box<dyn Error>> {
+    if !all_seasons {
+        return Ok(vec![load_cached_season(cache_dir, season_id)?]);
+    }
+
+    let snapshots = load_season_snapshots(cache_dir);
+    if snapshots.is_empty() {
+        return Err(EMPTY_CACHE.into());
+    }
+
+    Ok(snapshots)
+}
@@ -0,0 +1,693 @@
+use std::{cell::RefCell, collections::HashMap, error::Error, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, DeserializeOwned, MapAccess, SeqAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+
+use crate::models::{ResponseDiagnostics, SkippedItem};
+
+// The error stopping the deserialization at a list whose path is needed to report its items (see
+// `deserialize_tracked`)
+const LIST_PATH_REQUEST: &str = "the path of the list is requested";
+
+/// A list item skipped because it could not be deserialized, with the paths relative to the
+/// deserialized value.
+struct Skipped {
+    item_path: String,
+    error_path: String,
+    error: String,
+}
+
+/// The differences between a value and the models found while deserializing it, with the paths
+/// relative to the value.
+#[derive(Default)]
+struct Findings {
+    skipped: Vec<Skipped>,
+    unexpected_fields: Vec<String>,
+}
+
+impl Findings {
+    fn is_empty(&self) -> bool {
+        self.skipped.is_empty() && self.unexpected_fields.is_empty()
+    }
+
+    // Add the findings of the list item at the path
+    fn extend_at(&mut self, item_path: &str, item: Findings) {
+        self.skipped
+            .extend(item.skipped.into_iter().map(|skipped| Skipped {
+                item_path: join_path(item_path, &skipped.item_path),
+                error_path: join_path(item_path, &skipped.error_path),
+                error: skipped.error,
+            }));
+        self.unexpected_fields.extend(
+            item.unexpected_fields
+                .iter()
+                .map(|field| join_path(item_path, field)),
+        );
+    }
+
+    fn into_diagnostics(self) -> ResponseDiagnostics {
+        let mut diagnostics = ResponseDiagnostics::default();
+        for field in self.unexpected_fields {
+            diagnostics
+                .unexpected_fields
+                .insert(without_indices(&field));
+        }
+        for skipped in self.skipped {
+            let missing_field = skipped
+                .error
+                .strip_prefix("missing field `")
+                .and_then(|rest| rest.split('`').next());
+            if let Some(field) = missing_field {
+                let field_path = join_path(&skipped.error_path, field);
+                diagnostics
+                    .missing_fields
+                    .insert(without_indices(&field_path));
+            }
+            diagnostics.skipped_items.push(SkippedItem {
+                path: skipped.item_path,
+                error: format!("{} at {}", skipped.error, skipped.error_path),
+            });
+        }
+        diagnostics
+    }
+}
+
+/// What was found in a list item while deserializing it.
+struct ItemRecord {
+    // The id of the list in its frame and the index of the item in the list
+    list: usize,
+    index: usize,
+    findings: Findings,
+}
+
+/// A value being deserialized with the diagnostics (the response, or a list item in it).
+#[derive(Default)]
+struct Frame {
+    // The number of the lists started in the current pass, the ids of the lists
+    lists: usize,
+    // The paths of the lists with findings, by the id of the list
+    list_paths: HashMap<usize, String>,
+    // The list which stopped the current pass to get its path
+    requested_list: Option<usize>,
+    items: Vec<ItemRecord>,
+}
+
+thread_local! {
+    // The frames of the values being deserialized with the diagnostics, the innermost last. Empty
+    // when no diagnostics are being collected.
+    static FRAMES: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+fn with_frame<R>(f: impl FnOnce(&mut Frame) -> R) -> Option<R> {
+    FRAMES.with_borrow_mut(|frames| frames.last_mut().map(f))
+}
+
+// Format the path of `serde_ignored` like `competitor.players[3].age`
+fn ignored_path(path: &serde_ignored::Path) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => {
+            format!("{}[{}]", ignored_path(parent), index)
+        }
+        serde_ignored::Path::Map { parent, key } => join_path(&ignored_path(parent), key),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => ignored_path(parent),
+    }
+}
+
+fn join_path(base: &str, path: &str) -> String {
+    match (base, path) {
+        (base, "") | (base, ".") => base.to_string(),
+        ("", path) | (".", path) => path.to_string(),
+        (base, path) if path.starts_with('[') => format!("{}{}", base, path),
+        (base, path) => format!("{}.{}", base, path),
+    }
+}
+
+// Remove the list indices, so that the same field of all the list items has the same path
+fn without_indices(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut in_index = false;
+    for c in path.chars() {
+        match c {
+            '[' => {
+                in_index = true;
+                result.push_str("[]");
+            }
+            ']' => in_index = false,
+            _ if !in_index => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+// Deserialize a value with the diagnostics. `deserialize` is called with the callback of the
+// fields unknown to the models and may be called more than once: the path of a list is not known
+// while its items are deserialized, so every list with findings stops the deserialization once
+// with an error, which `serde_path_to_error` reports at the path of the list, and the value is
+// deserialized again with the path known.
+fn deserialize_tracked<T, E: fmt::Display>(
+    mut deserialize: impl FnMut(
+        &mut dyn FnMut(serde_ignored::Path),
+    ) -> Result<T, serde_path_to_error::Error<E>>,
+) -> (Result<T, (String, String)>, Findings) {
+    FRAMES.with_borrow_mut(|frames| frames.push(Frame::default()));
+    let (result, unexpected_fields) = loop {
+        with_frame(|frame| {
+            frame.lists = 0;
+            frame.items.clear();
+        });
+        let mut unexpected_fields = Vec::new();
+        let result = deserialize(&mut |path| unexpected_fields.push(ignored_path(&path)));
+
+        match (
+            result,
+            with_frame(|frame| frame.requested_list.take()).flatten(),
+        ) {
+            (Err(err), Some(list)) if err.inner().to_string().contains(LIST_PATH_REQUEST) => {
+                let path = join_path("", &err.path().to_string());
+                with_frame(|frame| frame.list_paths.insert(list, path));
+            }
+            (result, _) => break (result, unexpected_fields),
+        }
+    };
+    let frame = FRAMES
+        .with_borrow_mut(Vec::pop)
+        .expect("The frame of the value is pushed above");
+
+    let mut findings = Findings {
+        skipped: Vec::new(),
+        unexpected_fields,
+    };
+    for item in frame.items {
+        let list_path = frame.list_paths.get(&item.list).map_or("", String::as_str);
+        findings.extend_at(&format!("{}[{}]", list_path, item.index), item.findings);
+    }
+    let result = result.map_err(|err| {
+        (
+            join_path("", &err.path().to_string()),
+            err.inner().to_string(),
+        )
+    });
+
+    (result, findings)
+}
+
+/// An XML element buffered as its attributes (`@name`), text (`$text`), and child elements in the
+/// document order, so that a list item can be deserialized on its own. It is written back to XML to
+/// deserialize the item, as the text of the elements and attributes is typed only by the models.
+#[derive(Debug)]
+enum XmlNode {
+    Text(String),
+    Element(Vec<(String, XmlNode)>),
+}
+
+impl<'de> Deserialize<'de> for XmlNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct XmlNodeVisitor;
+
+        impl<'de> Visitor<'de> for XmlNodeVisitor {
+            type Value = XmlNode;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an XML element or text")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(XmlNode::Text(value.to_string()))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(XmlNode::Element(Vec::new()))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut children = Vec::new();
+                while let Some(name) = map.next_key::<String>()? {
+                    children.push((name, map.next_value()?));
+                }
+                Ok(XmlNode::Element(children))
+            }
+        }
+
+        deserializer.deserialize_any(XmlNodeVisitor)
+    }
+}
+
+impl Serialize for XmlNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            XmlNode::Text(text) => serializer.serialize_str(text),
+            XmlNode::Element(children) => {
+                let mut map = serializer.serialize_map(Some(children.len()))?;
+                for (name, child) in children {
+                    map.serialize_entry(name, child)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// A buffered list item.
+enum ListItem {
+    Json(Value),
+    Xml(XmlNode),
+}
+
+// Deserialize the item at the index of the list (the id of the list in the current frame, `None`
+// when no diagnostics are being collected), recording what was found in it. Returns `None` if the
+// item cannot be deserialized.
+fn deserialize_item<T: DeserializeOwned>(
+    item: ListItem,
+    list: Option<usize>,
+    index: usize,
+) -> Option<T> {
+    let xml = match &item {
+        ListItem::Json(_) => String::new(),
+        ListItem::Xml(node) => match quick_xml::se::to_string_with_root("item", node) {
+            Ok(xml) => xml,
+            Err(err) => {
+                let findings = Findings {
+                    skipped: vec![Skipped {
+                        item_path: String::new(),
+                        error_path: String::new(),
+                        error: err.to_string(),
+                    }],
+                    unexpected_fields: Vec::new(),
+                };
+                record_item(list, index, findings);
+                return None;
+            }
+        },
+    };
+
+    let Some(list) = list else {
+        return match item {
+            ListItem::Json(value) => serde_json::from_value(value).ok(),
+            ListItem::Xml(_) => quick_xml::de::from_str(&xml).ok(),
+        };
+    };
+
+    let (result, mut findings) = match &item {
+        ListItem::Json(value) => deserialize_tracked(|mut on_ignored| {
+            serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+                value,
+                &mut on_ignored,
+            ))
+        }),
+        ListItem::Xml(_) => deserialize_tracked(|mut on_ignored| {
+            let mut deserializer = quick_xml::de::Deserializer::from_str(&xml);
+            serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+                &mut deserializer,
+                &mut on_ignored,
+            ))
+        }),
+    };
+    if let Err((error_path, error)) = &result {
+        findings.skipped.push(Skipped {
+            item_path: String::new(),
+            error_path: error_path.clone(),
+            error: error.clone(),
+        });
+    }
+    record_item(Some(list), index, findings);
+
+    result.ok()
+}
+
+fn record_item(list: Option<usize>, index: usize, findings: Findings) {
+    if let (Some(list), false) = (list, findings.is_empty()) {
+        with_frame(|frame| {
+            frame.items.push(ItemRecord {
+                list,
+                index,
+                findings,
+            })
+        });
+    }
+}
+
+// Start a list in the current frame, returning its id (`None` when no diagnostics are being
+// collected)
+fn start_list() -> Option<usize> {
+    with_frame(|frame| {
+        frame.lists += 1;
+        frame.lists - 1
+    })
+}
+
+// Returns `true` if the path of the list is needed for its findings and not known yet, marking the
+// list as the one whose path the current pass requests
+fn request_list_path(list: Option<usize>) -> bool {
+    let Some(list) = list else {
+        return false;
+    };
+    with_frame(|frame| {
+        let needed = !frame.list_paths.contains_key(&list)
+            && frame.items.iter().any(|item| item.list == list);
+        if needed {
+            frame.requested_list = Some(list);
+        }
+        needed
+    })
+    .unwrap_or_default()
+}
+
+/// Deserializes a list which is an array in JSON (`"players": [{...}, {...}]`) and a wrapper
+/// element around the repeated item elements in XML (`<players><player/><player/></players>`).
+///
+/// The items which cannot be deserialized are skipped instead of failing the whole list, in both
+/// formats, and reported by [`deserialize_json_with_diagnostics`] and
+/// [`deserialize_xml_with_diagnostics`] with their paths (e.g. `competitor.players[3]`).
+///
+/// Use it with `#[serde(deserialize_with = "deserialize_list")]` on the `Vec` fields of the models
+/// and the API responses.
+pub fn deserialize_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    struct ListVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: DeserializeOwned> Visitor<'de> for ListVisitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a list or an element wrapping the list items")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            // Every item is buffered, so that a broken item does not break the rest of the list
+            let list = start_list();
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            let mut index = 0;
+            while let Some(value) = seq.next_element::<Value>()? {
+                items.extend(deserialize_item(ListItem::Json(value), list, index));
+                index += 1;
+            }
+
+            if request_list_path(list) {
+                return Err(de::Error::custom(LIST_PATH_REQUEST));
+            }
+            Ok(items)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            // Every child element of the wrapper is a list item, whatever its name
+            let list = start_list();
+            let mut items = Vec::new();
+            let mut index = 0;
+            while map.next_key::<String>()?.is_some() {
+                for node in map.next_value::<Vec<XmlNode>>()? {
+                    items.extend(deserialize_item(ListItem::Xml(node), list, index));
+                    index += 1;
+                }
+            }
+
+            if request_list_path(list) {
+                return Err(de::Error::custom(LIST_PATH_REQUEST));
+            }
+            Ok(items)
+        }
+
+        // An empty XML wrapper element (`<players/>`)
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            match value.trim() {
+                "" => Ok(Vec::new()),
+                _ => Err(E::invalid_type(de::Unexpected::Str(value), &self)),
+            }
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(Vec::new())
+        }
+    }
+
+    deserializer.deserialize_any(ListVisitor(PhantomData))
+}
+
+/// Deserializes the JSON response and reports the differences between the response and the
+/// models: the list items skipped because they could not be deserialized (see
+/// [`deserialize_list`]), the required fields missing in them, and the fields which are not in the
+/// models.
+///
+/// # Errors
+///
+/// Returns an error with the JSON path of the failed field if the body is not a valid JSON or the
+/// response itself (not one of its list items) cannot be deserialized.
+pub fn deserialize_json_with_diagnostics<Response: DeserializeOwned>(
+    body: &str,
+) -> Result<(Response, ResponseDiagnostics), Box<dyn Error>> {
+    let root: Value = serde_json::from_str(body)?;
+    let (result, findings) = deserialize_tracked(|mut on_ignored| {
+        serde_path_to_error::deserialize(serde_ignored::Deserializer::new(&root, &mut on_ignored))
+    });
+    let response = result.map_err(|(path, error)| format!("{} at {}", error, path))?;
+
+    Ok((response, findings.into_diagnostics()))
+}
+
+/// Deserializes the XML response and reports the differences between the response and the models
+/// the same way as [`deserialize_json_with_diagnostics`]. The attributes are reported with the `@`
+/// prefix, e.g. `competitor.players[].@type`.
+///
+/// # Errors
+///
+/// Returns an error with the path of the failed element if the body is not a valid XML or the
+/// response itself (not one of its list items) cannot be deserialized.
+pub fn deserialize_xml_with_diagnostics<Response: DeserializeOwned>(
+    body: &str,
+) -> Result<(Response, ResponseDiagnostics), Box<dyn Error>> {
+    let (result, findings) = deserialize_tracked(|mut on_ignored| {
+        let mut deserializer = quick_xml::de::Deserializer::from_str(body);
+        serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+            &mut deserializer,
+            &mut on_ignored,
+        ))
+    });
+    let response = result.map_err(|(path, error)| format!("{} at {}", error, path))?;
+
+    Ok((response, findings.into_diagnostics()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{api_responses::CompetitorStatisticsApiResponse, models::PlayerStatistics};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Player {
+        id: String,
+        goals: u8,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Team {
+        #[serde(deserialize_with = "deserialize_list")]
+        players: Vec<Player>,
+    }
+
+    fn player(id: &str, goals: u8) -> Player {
+        Player {
+            id: id.to_string(),
+            goals,
+        }
+    }
+
+    #[test]
+    fn json_list_skips_broken_items() {
+        let body =
+            r#"{"players": [{"id": "a", "goals": 1}, {"id": "b"}, {"id": "c", "goals": 3}]}"#;
+        let team: Team = serde_json::from_str(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1), player("c", 3)]);
+    }
+
+    #[test]
+    fn json_diagnostics_report_skipped_items_and_fields() {
+        let body = r#"{
+            "players": [
+                {"id": "a", "goals": 1, "age": 25},
+                {"id": "b"},
+                {"id": "c", "goals": "many"}
+            ],
+            "venue": "Emirates"
+        }"#;
+        let (team, diagnostics) = deserialize_json_with_diagnostics::<Team>(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1)]);
+        assert_eq!(
+            diagnostics
+                .skipped_items
+                .iter()
+                .map(|item| item.path.as_str())
+                .collect::<Vec<_>>(),
+            ["players[1]", "players[2]"]
+        );
+        assert!(
+            diagnostics.skipped_items[1]
+                .error
+                .ends_with("at players[2].goals"),
+            "{}",
+            diagnostics.skipped_items[1].error
+        );
+        assert_eq!(
+            diagnostics.missing_fields,
+            BTreeSet::from(["players[].goals".to_string()])
+        );
+        assert_eq!(
+            diagnostics.unexpected_fields,
+            BTreeSet::from(["players[].age".to_string(), "venue".to_string()])
+        );
+    }
+
+    #[test]
+    fn json_diagnostics_of_matching_response_are_empty() {
+        let body = r#"{"players": [{"id": "a", "goals": 1}]}"#;
+        let (_, diagnostics) = deserialize_json_with_diagnostics::<Team>(body).unwrap();
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn json_diagnostics_fail_on_broken_response() {
+        let err = deserialize_json_with_diagnostics::<Team>(r#"{"players": 3}"#).unwrap_err();
+
+        assert!(err.to_string().ends_with("at players"), "{}", err);
+    }
+
+    #[test]
+    fn xml_list_reads_items_of_wrapper_element() {
+        let body = r#"<team><players><player><id>a</id><goals>1</goals></player><player><id>b</id><goals>2</goals></player></players></team>"#;
+        let team: Team = quick_xml::de::from_str(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1), player("b", 2)]);
+    }
+
+    #[test]
+    fn xml_list_of_empty_wrapper_element_is_empty() {
+        let team: Team = quick_xml::de::from_str("<team><players/></team>").unwrap();
+        assert_eq!(team.players, []);
+
+        let team: Team = quick_xml::de::from_str("<team><players></players></team>").unwrap();
+        assert_eq!(team.players, []);
+    }
+
+    #[test]
+    fn json_diagnostics_report_duplicate_items_at_their_own_paths() {
+        let body = r#"{"players": [{"id": "b"}, {"id": "a", "goals": 1}, {"id": "b"}]}"#;
+        let (team, diagnostics) = deserialize_json_with_diagnostics::<Team>(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1)]);
+        assert_eq!(
+            diagnostics
+                .skipped_items
+                .iter()
+                .map(|item| item.path.as_str())
+                .collect::<Vec<_>>(),
+            ["players[0]", "players[2]"]
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct League {
+        #[serde(deserialize_with = "deserialize_list")]
+        teams: Vec<Team>,
+    }
+
+    #[test]
+    fn json_diagnostics_report_nested_items_at_full_paths() {
+        let body = r#"{"teams": [
+            {"players": [{"id": "a", "goals": 1}]},
+            {"players": [{"id": "a", "goals": 1}, {"id": "b", "age": 30}]}
+        ]}"#;
+        let (league, diagnostics) = deserialize_json_with_diagnostics::<League>(body).unwrap();
+
+        assert_eq!(league.teams[1].players, [player("a", 1)]);
+        assert_eq!(diagnostics.skipped_items.len(), 1);
+        assert_eq!(diagnostics.skipped_items[0].path, "teams[1].players[1]");
+        assert_eq!(
+            diagnostics.skipped_items[0].error,
+            "missing field `goals` at teams[1].players[1]"
+        );
+        assert_eq!(
+            diagnostics.missing_fields,
+            BTreeSet::from(["teams[].players[].goals".to_string()])
+        );
+        assert_eq!(
+            diagnostics.unexpected_fields,
+            BTreeSet::from(["teams[].players[].age".to_string()])
+        );
+    }
+
+    #[test]
+    fn player_without_statistics_is_kept_with_zero_values() {
+        let body = r#"{"competitor": {"id": "sr:competitor:17", "name": "Manchester City", "players": [
+            {"id": "sr:player:1", "name": "Haaland, Erling", "statistics": {"goals_scored": 27}},
+            {"id": "sr:player:2", "name": "Ortega, Stefan"}
+        ]}}"#;
+        let (response, diagnostics) = deserialize_json_with_diagnostics::<
+            CompetitorStatisticsApiResponse<crate::models::Player>,
+        >(body)
+        .unwrap();
+
+        let players = response.competitor.players;
+        assert_eq!(players.len(), 2);
+        assert_eq!(players[0].statistics.goals_scored, 27);
+        assert_eq!(players[0].statistics.assists, 0);
+        assert_eq!(players[1].statistics, PlayerStatistics::default());
+        assert!(diagnostics.skipped_items.is_empty());
+    }
+
+    #[test]
+    fn xml_list_skips_broken_items() {
+        let body = r#"<team><players><player><id>a</id><goals>1</goals></player><player><id>b</id></player><player><id>c</id><goals>3</goals></player></players></team>"#;
+        let team: Team = quick_xml::de::from_str(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1), player("c", 3)]);
+    }
+
+    #[test]
+    fn xml_diagnostics_report_skipped_items() {
+        let body = r#"<team><players><player><id>a</id><goals>1</goals><age>25</age></player><player><id>b</id></player></players></team>"#;
+        let (team, diagnostics) = deserialize_xml_with_diagnostics::<Team>(body).unwrap();
+
+        assert_eq!(team.players, [player("a", 1)]);
+        assert_eq!(diagnostics.skipped_items.len(), 1);
+        assert_eq!(diagnostics.skipped_items[0].path, "players[1]");
+        assert_eq!(
+            diagnostics.missing_fields,
+            BTreeSet::from(["players[].goals".to_string()])
+        );
+        assert_eq!(
+            diagnostics.unexpected_fields,
+            BTreeSet::from(["players[].age".to_string()])
+        );
+    }
+
+    #[test]
+    fn xml_items_keep_attributes_and_text() {
+        let body = r#"<competitor_statistics><competitor id="sr:competitor:17" name="Manchester City"><players><player id="sr:player:1" name="Haaland, Erling"><statistics goals_scored="27" assists="5"/></player></players></competitor></competitor_statistics>"#;
+        let (response, diagnostics) = deserialize_xml_with_diagnostics::<
+            CompetitorStatisticsApiResponse<crate::models::Player>,
+        >(body)
+        .unwrap();
+
+        let players = response.competitor.players;
+        assert_eq!(players[0].name, "Haaland, Erling");
+        assert_eq!(players[0].statistics.goals_scored, 27);
+        assert_eq!(players[0].statistics.assists, 5);
+        assert!(diagnostics.skipped_items.is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Mutex;
+
+use crate::models::{DiagnosticsReport, ResponseDiagnostics};
+
+// The diagnostics of all the API responses fetched by the process
+static DIAGNOSTICS_REPORT: Mutex<DiagnosticsReport> = Mutex::new(DiagnosticsReport {
+    responses: std::collections::BTreeMap::new(),
+});
+
+/// Adds the diagnostics of the response to the report, replacing the previous diagnostics of the
+/// same URL (if any).
+pub fn record_response_diagnostics(url: impl Into<String>, diagnostics: ResponseDiagnostics) {
+    DIAGNOSTICS_REPORT
+        .lock()
+        .expect("Diagnostics lock is poisoned")
+        .responses
+        .insert(url.into(), diagnostics);
+}
+
+/// Returns the diagnostics of all the API responses fetched so far whose data did not match the
+/// models (see [`crate::enums::ResponseFormat::deserialize_with_diagnostics`]).
+pub fn diagnostics_report() -> DiagnosticsReport {
+    DIAGNOSTICS_REPORT
+        .lock()
+        .expect("Diagnostics lock is poisoned")
+        .clone()
+}
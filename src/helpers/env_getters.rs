@@ -1,25 +1,81 @@
-use crate::services::BaseInfoService;
+use std::path::PathBuf;
 
-fn get_api_base_url() -> String {
-    std::env::var("SPORT_RADAR_API_BASE_URL").expect("API url variable not found")
+pub fn get_api_base_url() -> Result<String, String> {
+    std::env::var("SPORT_RADAR_API_BASE_URL").map_err(|_| "API url variable not found".into())
 }
 
-fn get_api_key() -> String {
-    std::env::var("SPORT_RADAR_API_KEY").expect("API key not found")
+pub fn get_api_key() -> Result<String, String> {
+    std::env::var("SPORT_RADAR_API_KEY").map_err(|_| "API key not found".into())
 }
 
-fn get_access_level() -> String {
-    std::env::var("SPORT_RADAR_API_ACCESS_LEVEL").expect("API access level variable not found")
+pub fn get_access_level() -> Result<String, String> {
+    std::env::var("SPORT_RADAR_API_ACCESS_LEVEL")
+        .map_err(|_| "API access level variable not found".into())
 }
 
-fn get_language_code() -> String {
+pub fn get_language_code() -> String {
     std::env::var("SPORT_RADAR_API_LANGUAGE_CODE").unwrap_or_else(|_| "en".to_string())
 }
 
-fn get_format() -> String {
+pub fn get_format() -> String {
     std::env::var("SPORT_RADAR_API_FORMAT").unwrap_or_else(|_| "json".to_string())
 }
 
+// Get a setting of a single endpoint, e.g. `SPORT_RADAR_PLAYER_PROFILE_VERSION`
+pub fn get_endpoint_setting(endpoint_name: &str, setting: &str) -> Option<String> {
+    std::env::var(format!(
+        "SPORT_RADAR_{}_{}",
+        endpoint_name.to_uppercase(),
+        setting
+    ))
+    .ok()
+}
+
+// Get the path of the JSON file listing the sport APIs reachable with the API key (if any)
+pub fn get_sports_config_path() -> Option<PathBuf> {
+    std::env::var("SPORT_RADAR_SPORTS_CONFIG")
+        .ok()
+        .map(Into::into)
+}
+
+// Get the directory of the local cache
+pub fn get_cache_dir() -> PathBuf {
+    std::env::var("SPORT_RADAR_CACHE_DIR")
+        .unwrap_or_else(|_| ".sportradar_cache".to_string())
+        .into()
+}
+
+// Get the path of the file counting the API requests, in the local cache directory by default
+pub fn get_quota_file() -> PathBuf {
+    std::env::var("SPORT_RADAR_QUOTA_FILE")
+        .map(Into::into)
+        .unwrap_or_else(|_| get_cache_dir().join("quota.json"))
+}
+
+// Get the number of the API requests allowed per month (if any)
+pub fn get_monthly_budget() -> Result<Option<u64>, String> {
+    std::env::var("SPORT_RADAR_MONTHLY_BUDGET")
+        .ok()
+        .map(|budget| {
+            budget
+                .parse()
+                .map_err(|_| format!("Invalid monthly budget: {}", budget))
+        })
+        .transpose()
+}
+
+// Get the number of the requests per second allowed for the API key (1 for the trial keys)
+pub fn get_rate_limit() -> Result<u32, String> {
+    match std::env::var("SPORT_RADAR_RATE_LIMIT") {
+        Ok(rate_limit) => rate_limit
+            .parse()
+            .ok()
+            .filter(|rate_limit| *rate_limit > 0)
+            .ok_or_else(|| format!("Invalid rate limit: {}", rate_limit)),
+        Err(_) => Ok(1),
+    }
+}
+
 pub fn get_allowed_countries() -> Vec<String> {
     std::env::var("ALLOWED_COUNTRIES")
         .unwrap_or_else(|_| "England,Germany,Italy,Spain,USA,Austria".into())
@@ -38,18 +94,3 @@ pub fn get_allowed_competitions() -> Vec<String> {
         .map(|s| s.to_string())
         .collect()
 }
-
-pub fn get_api_url<T: BaseInfoService>(sport: &T, endpoint: impl Into<String>) -> String {
-    let sport = sport.name();
-
-    format!(
-        "{}/{}/{}/v4/{}/{}.{}?api_key={}",
-        get_api_base_url(),
-        sport,
-        get_access_level(),
-        get_language_code(),
-        endpoint.into(),
-        get_format(),
-        get_api_key()
-    )
-}
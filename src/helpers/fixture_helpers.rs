@@ -0,0 +1,134 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use reqwest::Url;
+
+use super::cache_helpers::file_safe_id;
+
+/// Removes the `api_key` query parameter from the URL, so that the URL can be saved or logged
+/// without leaking the API key.
+pub fn scrub_api_key(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+
+    let query: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(name, _)| name != "api_key")
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if query.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(query);
+    }
+
+    parsed.to_string()
+}
+
+/// Replaces the values of the `api_key` query parameters in the text, e.g. the message of an
+/// error which has the requested URL in it, so that the text can be logged or returned to a client.
+pub fn redact_api_key(text: &str) -> String {
+    const PARAMETER: &str = "api_key=";
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(PARAMETER) {
+        let (before, after) = rest.split_at(start + PARAMETER.len());
+        redacted.push_str(before);
+        redacted.push_str("[redacted]");
+        let end = after
+            .find(|c: char| c == '&' || c == '"' || c == '\'' || c == ')' || c.is_whitespace())
+            .unwrap_or(after.len());
+        rest = &after[end..];
+    }
+    redacted.push_str(rest);
+
+    redacted
+}
+
+/// Removes the API key from the error: the URL of a [`reqwest::Error`] is left out (keeping the
+/// error and its status), and the API key is redacted from the message of any other error.
+pub fn scrub_api_key_from_error(err: Box<dyn Error>) -> Box<dyn Error> {
+    match err.downcast::<reqwest::Error>() {
+        Ok(err) => Box::new(err.without_url()),
+        Err(err) => {
+            let message = err.to_string();
+            if message.contains("api_key=") {
+                redact_api_key(&message).into()
+            } else {
+                err
+            }
+        }
+    }
+}
+
+/// Returns the path of the fixture file storing the response of the URL in the fixture directory.
+///
+/// The path mirrors the URL path without the host, e.g. the response of
+/// `https://api.sportradar.com/soccer/trial/v4/en/competitions.json?api_key=...` is stored in
+/// `<dir>/soccer/trial/v4/en/competitions.json`. The query parameters other than the API key are
+/// appended to the file name, and the colons of the Sportradar ids are replaced with underscores.
+pub fn fixture_path(dir: &Path, url: &str) -> PathBuf {
+    let scrubbed = scrub_api_key(url);
+    let (path, query) = match Url::parse(&scrubbed) {
+        Ok(parsed) => (
+            parsed.path().to_string(),
+            parsed.query().map(str::to_string),
+        ),
+        Err(_) => (scrubbed, None),
+    };
+
+    let mut fixture = dir.to_path_buf();
+    fixture.extend(
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(file_safe_id),
+    );
+    if let Some(query) = query {
+        let file_name = fixture
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let suffix = file_safe_id(&query.replace(['&', '='], "_"));
+        fixture.set_file_name(match file_name.rsplit_once('.') {
+            Some((stem, extension)) => format!("{}__{}.{}", stem, suffix, extension),
+            None => format!("{}__{}", file_name, suffix),
+        });
+    }
+
+    fixture
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_api_key_replaces_every_key() {
+        let text = "error sending request for url \
+            (https://api.sportradar.com/soccer/trial/v4/en/competitions.json?api_key=secret&x=1): \
+            retried https://mock/a.json?api_key=other";
+
+        let redacted = redact_api_key(text);
+
+        assert!(!redacted.contains("secret"));
+        assert!(!redacted.contains("other"));
+        assert!(redacted.contains("competitions.json?api_key=[redacted]&x=1)"));
+        assert!(redacted.ends_with("a.json?api_key=[redacted]"));
+    }
+
+    #[test]
+    fn scrub_api_key_from_error_redacts_other_errors() {
+        let err: Box<dyn Error> = "failed: https://mock/a.json?api_key=secret".into();
+
+        let scrubbed = scrub_api_key_from_error(err);
+
+        assert_eq!(
+            scrubbed.to_string(),
+            "failed: https://mock/a.json?api_key=[redacted]"
+        );
+    }
+}
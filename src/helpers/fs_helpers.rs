@@ -0,0 +1,19 @@
+use std::{fs, io, path::Path};
+
+/// Writes the data to the file, creating its directory if needed. The data is written to a
+/// temporary file next to it which is then renamed, so a concurrent reader or an interrupted write
+/// never leaves a truncated file behind.
+pub fn write_atomically(path: &Path, data: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_name);
+
+    fs::write(&temp_path, data)?;
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
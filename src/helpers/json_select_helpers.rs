@@ -0,0 +1,150 @@
+use serde_json::Value;
+
+// A step of the selector path
+enum Segment {
+    Key(String),
+    Index(usize),
+    All,
+}
+
+// Parse a jq-style selector, e.g. `.competitor.players[0].name` or `$.competitor.players[].name`
+fn parse_selector(selector: &str) -> Result<Vec<Segment>, String> {
+    let invalid = || format!("Invalid selector: {}", selector);
+    let mut rest = selector.trim().strip_prefix('$').unwrap_or(selector.trim());
+    let mut segments = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            let key = &after_dot[..end];
+            match key {
+                // `.` alone selects the whole value, `.[0]` is the same as `[0]`
+                "" => {}
+                "*" => segments.push(Segment::All),
+                _ => segments.push(Segment::Key(key.to_string())),
+            }
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let (index, after) = after_bracket.split_once(']').ok_or_else(invalid)?;
+            segments.push(match index.trim() {
+                "" | "*" => Segment::All,
+                index => Segment::Index(index.parse().map_err(|_| invalid())?),
+            });
+            rest = after;
+        } else {
+            return Err(invalid());
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Selects a part of the JSON value with a jq-style selector: `.key` selects a field, `[n]` an
+/// array item, and `[]` (or `[*]`, `.*`) all the items of an array or all the field values of an
+/// object, e.g. `.competitor.players[].name`. A leading `$` (as in JSONPath) is allowed.
+///
+/// # Returns
+/// The selected value, or the array of the selected values if the selector has `[]`. The missing
+/// fields and items are skipped in the array.
+///
+/// # Errors
+/// Returns an error if the selector is invalid or selects nothing.
+pub fn select_json(value: &Value, selector: &str) -> Result<Value, String> {
+    let segments = parse_selector(selector)?;
+    let selects_many = segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::All));
+
+    let mut selected = vec![value];
+    for segment in &segments {
+        selected = selected
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (segment, value) {
+                    (Segment::Key(key), value) => value.get(key).into_iter().collect(),
+                    (Segment::Index(index), value) => value.get(index).into_iter().collect(),
+                    (Segment::All, Value::Array(items)) => items.iter().collect(),
+                    (Segment::All, Value::Object(fields)) => fields.values().collect(),
+                    (Segment::All, _) => Vec::new(),
+                }
+            })
+            .collect();
+    }
+
+    match (selects_many, selected.as_slice()) {
+        (true, _) => Ok(Value::Array(selected.into_iter().cloned().collect())),
+        (false, [value]) => Ok((*value).clone()),
+        (false, _) => Err(format!("Nothing matches the selector: {}", selector)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn profile() -> Value {
+        json!({
+            "competitor": {
+                "name": "Arsenal FC",
+                "players": [
+                    {"name": "Saka", "jersey_number": 7},
+                    {"name": "Ødegaard", "jersey_number": 8},
+                    {"name": "Rice"}
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn select_fields_and_items() {
+        assert_eq!(
+            select_json(&profile(), ".competitor.name"),
+            Ok(json!("Arsenal FC"))
+        );
+        assert_eq!(
+            select_json(&profile(), "$.competitor.players[1].name"),
+            Ok(json!("Ødegaard"))
+        );
+        assert_eq!(select_json(&profile(), "."), Ok(profile()));
+        assert_eq!(select_json(&profile(), ""), Ok(profile()));
+    }
+
+    #[test]
+    fn select_all_items_skips_missing_fields() {
+        assert_eq!(
+            select_json(&profile(), ".competitor.players[].jersey_number"),
+            Ok(json!([7, 8]))
+        );
+        assert_eq!(
+            select_json(&profile(), ".competitor.players[*].name"),
+            Ok(json!(["Saka", "Ødegaard", "Rice"]))
+        );
+        assert_eq!(
+            select_json(&json!({"a": 1, "b": 2}), ".*"),
+            Ok(json!([1, 2]))
+        );
+        // Selecting all the items of a scalar selects nothing, but it is still an array
+        assert_eq!(select_json(&profile(), ".competitor.name[]"), Ok(json!([])));
+    }
+
+    #[test]
+    fn select_nothing_is_error() {
+        assert_eq!(
+            select_json(&profile(), ".competitor.players[5]"),
+            Err("Nothing matches the selector: .competitor.players[5]".to_string())
+        );
+        assert!(select_json(&profile(), ".team").is_err());
+    }
+
+    #[test]
+    fn invalid_selectors_are_errors() {
+        for selector in ["competitor", ".players[0", ".players[x]", ".players[-1]"] {
+            assert_eq!(
+                select_json(&profile(), selector),
+                Err(format!("Invalid selector: {}", selector))
+            );
+        }
+    }
+}
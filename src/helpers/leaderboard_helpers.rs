@@ -0,0 +1,165 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    enums::Players,
+    services::{BaseInfoService, PlayerSeasonStatisticsService},
+};
+
+/// A player competing for a place in the leaderboard. A candidate with a higher value is better,
+/// and of two candidates with the same value the one first by the name, then by the id, is better,
+/// so the leaderboard does not depend on the order of the players (e.g. of a `HashMap`).
+struct Candidate<'a, Player> {
+    value: u8,
+    name: String,
+    id: String,
+    player: &'a Player,
+}
+
+impl<Player> Ord for Candidate<'_, Player> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        Ord::cmp(&self.value, &other.value)
+            .then_with(|| Ord::cmp(&other.name, &self.name))
+            .then_with(|| Ord::cmp(&other.id, &self.id))
+    }
+}
+
+impl<Player> PartialOrd for Candidate<'_, Player> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Player> PartialEq for Candidate<'_, Player> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<Player> Eq for Candidate<'_, Player> {}
+
+/// Gets at most `limit` players with the highest season value of the statistics, sorted in
+/// descending order (the players with the same value by the name, then by the id).
+///
+/// The players are selected with a heap of `limit` candidates, so only the selected players are
+/// cloned and the whole collection is never sorted.
+pub fn top_players<'a, Player: BaseInfoService + PlayerSeasonStatisticsService + Clone + 'a>(
+    players: impl IntoIterator<Item = &'a Player>,
+    statistics: Players,
+    limit: usize,
+) -> Vec<Player> {
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    // A min-heap keeping the best `limit` candidates seen so far, the worst of them on top
+    let mut heap = BinaryHeap::with_capacity(limit + 1);
+    for player in players {
+        heap.push(Reverse(Candidate {
+            value: statistics.season_value(player),
+            name: player.name(),
+            id: player.id(),
+            player,
+        }));
+        if heap.len() > limit {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(candidate)| candidate.player.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::Player;
+
+    fn player(name: &str, goals: u8, assists: u8) -> Player {
+        player_with_id(&format!("sr:player:{}", name), name, goals, assists)
+    }
+
+    fn player_with_id(id: &str, name: &str, goals: u8, assists: u8) -> Player {
+        serde_json::from_value(json!({
+            "id": id,
+            "name": name,
+            "statistics": {"goals_scored": 0, "assists": 0},
+            "season_statistics": {"goals_scored": goals, "assists": assists},
+        }))
+        .unwrap()
+    }
+
+    fn players() -> Vec<Player> {
+        vec![
+            player("Kane", 27, 7),
+            player("Haaland", 27, 5),
+            player("Palmer", 22, 11),
+            player("Watkins", 19, 13),
+            player("Solanke", 19, 3),
+        ]
+    }
+
+    fn names(players: &[Player]) -> Vec<String> {
+        players.iter().map(BaseInfoService::name).collect()
+    }
+
+    #[test]
+    fn top_players_are_sorted_by_metric() {
+        let top = top_players(&players(), Players::TopAssistants, 3);
+
+        assert_eq!(names(&top), ["Watkins", "Palmer", "Kane"]);
+    }
+
+    #[test]
+    fn top_players_with_same_value_are_sorted_by_name_then_id() {
+        let top = top_players(&players(), Players::TopGoalScorers, 5);
+        assert_eq!(
+            names(&top),
+            ["Haaland", "Kane", "Palmer", "Solanke", "Watkins"]
+        );
+
+        // The tie at the limit goes to the player first by the name
+        let top = top_players(&players(), Players::TopGoalScorers, 4);
+        assert_eq!(names(&top), ["Haaland", "Kane", "Palmer", "Solanke"]);
+
+        // Two players with the same name are ordered by the id
+        let mut players = players();
+        players.push(player_with_id("sr:player:0", "Kane", 27, 0));
+        let top = top_players(&players, Players::TopGoalScorers, 2);
+        let ids: Vec<String> = top.iter().map(BaseInfoService::id).collect();
+        assert_eq!(ids, ["sr:player:Haaland", "sr:player:0"]);
+    }
+
+    #[test]
+    fn top_players_do_not_depend_on_the_order_of_the_players() {
+        let mut players = players();
+        players.push(player_with_id("sr:player:0", "Kane", 27, 0));
+        let expected = top_players(&players, Players::TopGoalScorers, 3);
+
+        // Every order of the players, as a `HashMap` may iterate them in any of them
+        for shuffled in players.iter().permutations(players.len()) {
+            let top = top_players(shuffled, Players::TopGoalScorers, 3);
+            assert_eq!(top, expected);
+        }
+        let ids: Vec<String> = expected.iter().map(BaseInfoService::id).collect();
+        assert_eq!(ids, ["sr:player:Haaland", "sr:player:0", "sr:player:Kane"]);
+    }
+
+    #[test]
+    fn top_players_with_zero_limit_are_empty() {
+        assert!(top_players(&players(), Players::TopGoalScorers, 0).is_empty());
+    }
+
+    #[test]
+    fn top_players_with_limit_over_length_are_all_players() {
+        assert_eq!(
+            top_players(&players(), Players::TopGoalScorers, 100).len(),
+            5
+        );
+        assert!(top_players(&Vec::<Player>::new(), Players::TopGoalScorers, 10).is_empty());
+    }
+}
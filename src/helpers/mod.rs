@@ -1,5 +1,32 @@
-mod cli_helpers;
+mod cache_helpers;
+mod deserialize_helpers;
+mod diagnostics_helpers;
 mod env_getters;
+mod fixture_helpers;
+mod fs_helpers;
+mod json_select_helpers;
+mod leaderboard_helpers;
+mod progress_helpers;
+mod quota_helpers;
+mod search_helpers;
+mod time_helpers;
 
-pub use cli_helpers::{prompt_boolean, prompt_number, prompt_select};
-pub use env_getters::{get_allowed_competitions, get_allowed_countries, get_api_url};
+pub use cache_helpers::{
+    file_safe_id, load_cached_season, load_cached_seasons, load_season_snapshot,
+    load_season_snapshots, remove_season_snapshot, save_season_snapshot,
+};
+pub use deserialize_helpers::{
+    deserialize_json_with_diagnostics, deserialize_list, deserialize_xml_with_diagnostics,
+};
+pub use diagnostics_helpers::{diagnostics_report, record_response_diagnostics};
+pub use env_getters::*;
+pub use fixture_helpers::{fixture_path, redact_api_key, scrub_api_key, scrub_api_key_from_error};
+pub use json_select_helpers::select_json;
+pub use leaderboard_helpers::top_players;
+pub use progress_helpers::{report_retry, ProgressTracker};
+pub use quota_helpers::{
+    api_key_id, check_quota_budget, flush_quota_usage, mask_api_key, quota_usage,
+    record_api_request,
+};
+pub use search_helpers::{match_score, normalize_text, rank_matches};
+pub use time_helpers::{current_month, format_utc, now_utc, today};
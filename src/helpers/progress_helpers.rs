@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{models::FetchProgress, services::FetchProgressService};
+
+tokio::task_local! {
+    // The tracker of the bulk fetch running in the current task
+    static PROGRESS_TRACKER: Arc<ProgressTracker>;
+}
+
+/// Counts the finished, failed, and retried requests of a bulk fetch and notifies the observer
+/// (if any) about every change.
+#[derive(Debug)]
+pub struct ProgressTracker {
+    progress: Mutex<FetchProgress>,
+    observer: Option<Arc<dyn FetchProgressService>>,
+}
+
+impl ProgressTracker {
+    pub fn new(total: usize, observer: Option<Arc<dyn FetchProgressService>>) -> Self {
+        Self {
+            progress: Mutex::new(FetchProgress {
+                total,
+                ..FetchProgress::default()
+            }),
+            observer,
+        }
+    }
+
+    pub fn progress(&self) -> FetchProgress {
+        *self.progress.lock().expect("Progress lock is poisoned")
+    }
+
+    // Apply the change and notify the observer with the updated progress
+    fn update(&self, change: impl FnOnce(&mut FetchProgress)) {
+        let progress = {
+            let mut progress = self.progress.lock().expect("Progress lock is poisoned");
+            change(&mut progress);
+            *progress
+        };
+        if let Some(observer) = &self.observer {
+            observer.on_progress(&progress);
+        }
+    }
+
+    pub fn start(&self) {
+        if let Some(observer) = &self.observer {
+            observer.on_start(&self.progress());
+        }
+    }
+
+    /// Records a finished request.
+    pub fn complete(&self, succeeded: bool) {
+        self.update(|progress| {
+            progress.completed += 1;
+            if !succeeded {
+                progress.failed += 1;
+            }
+        });
+    }
+
+    /// Records a retried request.
+    pub fn retry(&self) {
+        self.update(|progress| progress.retries += 1);
+    }
+
+    pub fn finish(&self) {
+        if let Some(observer) = &self.observer {
+            observer.on_finish(&self.progress());
+        }
+    }
+
+    /// Runs the future with the tracker reachable by [`report_retry`], so that the HTTP client
+    /// can report its retries without knowing about the fetch.
+    pub async fn track<F: std::future::Future>(self: Arc<Self>, future: F) -> F::Output {
+        PROGRESS_TRACKER.scope(self, future).await
+    }
+}
+
+/// Reports a retried request to the tracker of the bulk fetch running in the current task (if
+/// any). Called by the retrying HTTP clients.
+pub fn report_retry() {
+    let _ = PROGRESS_TRACKER.try_with(|tracker| tracker.retry());
+}
@@ -0,0 +1,279 @@
+use std::{
+    fs::{self, OpenOptions},
+    io,
+    path::Path,
+    sync::Mutex,
+};
+
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+
+use crate::{config::ApiConfig, enums::Endpoint, models::QuotaUsage};
+
+use super::{
+    fs_helpers::write_atomically,
+    time_helpers::{current_month, today},
+};
+
+// The requests counted since the usage was last saved to the state file
+static PENDING_USAGE: Mutex<QuotaUsage> = Mutex::new(QuotaUsage::new());
+
+/// Returns the id the usage of the API key is counted by: the start of the SHA-256 hash of the
+/// key, so the usage of different keys is kept apart without storing the keys.
+pub fn api_key_id(api_key: &str) -> String {
+    Sha256::digest(api_key.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Returns the API key with all but the first and the last 4 characters hidden, to show which key
+/// the usage is of.
+pub fn mask_api_key(api_key: &str) -> String {
+    let chars: Vec<char> = api_key.chars().collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+
+    let start: String = chars[..4].iter().collect();
+    let end: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}...{}", start, end)
+}
+
+// Load the usage from the state file, a missing or unparsable file counts from zero
+fn load_quota_usage(path: &Path) -> QuotaUsage {
+    fs::read(path)
+        .ok()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the usage of all the API keys: the usage saved in the state file (see
+/// [`ApiConfig::quota_file`]) and the requests of this process not saved yet (see
+/// [`record_api_request`]).
+pub fn quota_usage(quota_file: &Path) -> QuotaUsage {
+    let mut quota_usage = load_quota_usage(quota_file);
+    quota_usage.merge(&PENDING_USAGE.lock().expect("Quota lock is poisoned"));
+    quota_usage
+}
+
+/// Counts the request to the URL for its API key, endpoint, and today's date. Called by the HTTP
+/// clients for every sent request, the retries included.
+///
+/// The request is counted in memory only, the usage is saved to the state file by
+/// [`flush_quota_usage`].
+pub fn record_api_request(url: &str) {
+    let Ok(url) = Url::parse(url) else {
+        return;
+    };
+    let api_key = url
+        .query_pairs()
+        .find(|(name, _)| name == "api_key")
+        .map(|(_, value)| value.into_owned())
+        .unwrap_or_default();
+    let endpoint = Endpoint::name_of_path(url.path()).unwrap_or("other");
+
+    let key = api_key_id(&api_key);
+    let mut pending = PENDING_USAGE.lock().expect("Quota lock is poisoned");
+    pending.record(&key, &today(), endpoint);
+    pending.masked_keys.insert(key, mask_api_key(&api_key));
+}
+
+/// Adds the requests counted by [`record_api_request`] since the last save to the state file (see
+/// [`ApiConfig::quota_file`]). Called after every fetch of the application state and before the
+/// CLI exits.
+///
+/// The file is read again and updated under a file lock, so the processes using the same file
+/// (e.g. the server and a CLI run) add up their requests instead of overwriting each other's.
+pub fn flush_quota_usage(quota_file: &Path) {
+    let mut pending = PENDING_USAGE.lock().expect("Quota lock is poisoned");
+    if pending.is_empty() {
+        return;
+    }
+
+    match add_to_quota_file(quota_file, &pending) {
+        Ok(()) => *pending = QuotaUsage::new(),
+        // The requests stay pending, to be saved by the next flush
+        Err(err) => {
+            tracing::warn!(error = %err, path = %quota_file.display(), "Failed to save the API usage")
+        }
+    }
+}
+
+// Add the usage to the usage saved in the state file, holding the lock file next to it while the
+// file is read and written
+fn add_to_quota_file(path: &Path, usage: &QuotaUsage) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut lock_name = path.file_name().unwrap_or_default().to_os_string();
+    lock_name.push(".lock");
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path.with_file_name(lock_name))?;
+    lock.lock()?;
+
+    let mut quota_usage = load_quota_usage(path);
+    quota_usage.merge(usage);
+    let data = serde_json::to_vec_pretty(&quota_usage).map_err(io::Error::other)?;
+    // The lock is released when the lock file is closed
+    write_atomically(path, &data)
+}
+
+/// Returns an error if sending the planned number of requests with the API key of the config would
+/// go over its monthly budget (if any).
+pub fn check_quota_budget(config: &ApiConfig, planned: usize) -> Result<(), String> {
+    let Some(budget) = config.monthly_budget else {
+        return Ok(());
+    };
+
+    check_month_budget(
+        &quota_usage(&config.quota_file),
+        &config.api_key,
+        &current_month(),
+        budget,
+        planned,
+    )
+}
+
+// Check the planned number of requests against the budget left for the key in the month
+fn check_month_budget(
+    quota_usage: &QuotaUsage,
+    api_key: &str,
+    month: &str,
+    budget: u64,
+    planned: usize,
+) -> Result<(), String> {
+    let used = quota_usage.month_total(&api_key_id(api_key), month);
+    let left = budget.saturating_sub(used);
+    if planned as u64 > left {
+        return Err(format!(
+            "The fetch needs {} API requests, but only {} of the monthly budget of {} are left",
+            planned, left, budget
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyUsage;
+
+    const API_KEY: &str = "abcd1234efgh5678";
+
+    fn usage() -> QuotaUsage {
+        let key = api_key_id(API_KEY);
+        let mut usage = QuotaUsage::default();
+        for _ in 0..3 {
+            usage.record(&key, "2024-05-19", "season_competitor_statistics");
+        }
+        usage.record(&key, "2024-05-20", "season_schedules");
+        usage.record(&key, "2024-06-01", "season_schedules");
+        usage.record(&api_key_id("another-api-key"), "2024-05-19", "competitions");
+        usage
+    }
+
+    #[test]
+    fn mask_api_key_keeps_first_and_last_characters() {
+        assert_eq!(mask_api_key(API_KEY), "abcd...5678");
+        assert_eq!(mask_api_key("short"), "*****");
+        assert_eq!(mask_api_key(""), "");
+    }
+
+    #[test]
+    fn api_key_id_tells_apart_keys_with_the_same_masked_form() {
+        let other_key = "abcdXXXXXXXX5678";
+        assert_eq!(mask_api_key(other_key), mask_api_key(API_KEY));
+
+        assert_ne!(api_key_id(other_key), api_key_id(API_KEY));
+        assert_eq!(api_key_id(API_KEY), api_key_id(API_KEY));
+        assert_eq!(api_key_id(API_KEY).len(), 16);
+        assert!(!api_key_id(API_KEY).contains("abcd"));
+    }
+
+    #[test]
+    fn quota_usage_counts_by_key_day_and_endpoint() {
+        let usage = usage();
+        let key = api_key_id(API_KEY);
+
+        assert_eq!(
+            usage.keys[&key]["2024-05-19"]["season_competitor_statistics"],
+            3
+        );
+        assert_eq!(usage.month_total(&key, "2024-05"), 4);
+        assert_eq!(usage.month_total(&key, "2024-06"), 1);
+        assert_eq!(usage.month_total(&key, "2024-07"), 0);
+        assert_eq!(usage.month_total("unknown", "2024-05"), 0);
+        assert_eq!(
+            usage.month_endpoints(&key, "2024-05"),
+            DailyUsage::from([
+                ("season_competitor_statistics".to_string(), 3),
+                ("season_schedules".to_string(), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn quota_file_adds_up_the_usage_of_every_flush() {
+        let path = std::env::temp_dir()
+            .join(format!("sportradar_quota_test_{}", std::process::id()))
+            .join("quota.json");
+        let key = api_key_id(API_KEY);
+        let mut first = QuotaUsage::default();
+        first.record(&key, "2024-05-19", "competitions");
+        first.masked_keys.insert(key.clone(), mask_api_key(API_KEY));
+        let mut second = QuotaUsage::default();
+        second.record(&key, "2024-05-19", "competitions");
+        second.record(&key, "2024-05-20", "season_schedules");
+
+        // Another process saves its requests between the flushes of this one
+        add_to_quota_file(&path, &first).unwrap();
+        add_to_quota_file(&path, &second).unwrap();
+        let saved = load_quota_usage(&path);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(saved.keys[&key]["2024-05-19"]["competitions"], 2);
+        assert_eq!(saved.month_total(&key, "2024-05"), 3);
+        assert_eq!(saved.display_key(&key), "abcd...5678");
+    }
+
+    #[test]
+    fn month_budget_allows_requests_left() {
+        assert_eq!(
+            check_month_budget(&usage(), API_KEY, "2024-05", 10, 6),
+            Ok(())
+        );
+        assert_eq!(
+            check_month_budget(&usage(), API_KEY, "2024-07", 10, 10),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn month_budget_refuses_requests_over_it() {
+        assert_eq!(
+            check_month_budget(&usage(), API_KEY, "2024-05", 10, 7),
+            Err(
+                "The fetch needs 7 API requests, but only 6 of the monthly budget of 10 are left"
+                    .to_string()
+            )
+        );
+        // The usage over the budget leaves no requests
+        assert!(check_month_budget(&usage(), API_KEY, "2024-05", 2, 1).is_err());
+    }
+
+    #[test]
+    fn no_budget_allows_any_requests() {
+        let config = ApiConfig {
+            api_key: API_KEY.to_string(),
+            monthly_budget: None,
+            ..ApiConfig::default()
+        };
+        assert_eq!(check_quota_budget(&config, usize::MAX), Ok(()));
+    }
+}
@@ -0,0 +1,219 @@
+use std::cmp::Reverse;
+
+use itertools::Itertools;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+// Scores of the match tiers. The tiers are far apart so that a better tier always wins over
+// a worse one, no matter the bonuses and penalties applied inside a tier.
+const EXACT_MATCH_SCORE: i64 = 10_000;
+const PREFIX_MATCH_SCORE: i64 = 8_000;
+const WORD_PREFIX_MATCH_SCORE: i64 = 6_000;
+const SUBSTRING_MATCH_SCORE: i64 = 4_000;
+const ALL_WORDS_MATCH_SCORE: i64 = 3_000;
+const FUZZY_MATCH_SCORE: i64 = 1_000;
+
+/// Folds the letters which have no Unicode decomposition (and therefore survive the NFD
+/// normalization) to their closest ASCII representation.
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'ø' | 'Ø' => "o",
+        'æ' | 'Æ' => "ae",
+        'œ' | 'Œ' => "oe",
+        'ß' => "ss",
+        'ł' | 'Ł' => "l",
+        'đ' | 'Đ' | 'ð' | 'Ð' => "d",
+        'þ' | 'Þ' => "th",
+        'ı' => "i",
+        _ => return None,
+    };
+
+    Some(folded)
+}
+
+/// Normalizes the text for searching: removes diacritics, folds special letters to ASCII,
+/// lowercases the text and collapses all non-alphanumeric characters into single spaces.
+///
+/// For example, both "Ødegaard" and "odegaard" are normalized to "odegaard", and
+/// "Saint-Maximin" is normalized to "saint maximin".
+pub fn normalize_text(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+
+    for c in text.nfd().filter(|c| !is_combining_mark(*c)) {
+        match fold_char(c) {
+            Some(folded) => normalized.push_str(folded),
+            None if c.is_alphanumeric() => normalized.extend(c.to_lowercase()),
+            None => normalized.push(' '),
+        }
+    }
+
+    normalized.split_whitespace().join(" ")
+}
+
+/// Scores a fuzzy subsequence match of the query in the candidate. Consecutive characters and
+/// characters at the start of words are rewarded, gaps between matched characters are penalized.
+/// Returns `None` if the query is not a subsequence of the candidate.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let candidate: Vec<char> = candidate.chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.chars().filter(|c| *c != ' ') {
+        let offset = candidate[position..]
+            .iter()
+            .position(|candidate_char| *candidate_char == query_char)?;
+        let index = position + offset;
+
+        if index == 0 || candidate[index - 1] == ' ' {
+            score += 10;
+        }
+        match previous_match {
+            Some(previous) if previous + 1 == index => score += 5,
+            Some(previous) => score -= (index - previous) as i64,
+            None => score -= index as i64,
+        }
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Scores how well the query matches the candidate. Both texts are compared after
+/// [`normalize_text`], so the matching ignores case, diacritics and punctuation.
+///
+/// The match quality tiers, from the best to the worst, are: exact match, prefix match,
+/// prefix of any word, substring, every query word being a prefix of some word, and a fuzzy
+/// subsequence match. Returns `None` if the candidate does not match the query at all.
+pub fn match_score(query: &str, candidate: &str) -> Option<i64> {
+    let query = normalize_text(query);
+    let candidate = normalize_text(candidate);
+
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    // Prefer shorter candidates inside the same tier ("Kane" over "Kanemann" for "kane")
+    let length_penalty = candidate.len().saturating_sub(query.len()) as i64;
+    let words: Vec<&str> = candidate.split(' ').collect();
+
+    let score = if candidate == query {
+        EXACT_MATCH_SCORE
+    } else if candidate.starts_with(&query) {
+        PREFIX_MATCH_SCORE - length_penalty
+    } else if words.iter().any(|word| word.starts_with(&query)) {
+        WORD_PREFIX_MATCH_SCORE - length_penalty
+    } else if let Some(index) = candidate.find(&query) {
+        SUBSTRING_MATCH_SCORE - index as i64 - length_penalty
+    } else if query
+        .split(' ')
+        .all(|query_word| words.iter().any(|word| word.starts_with(query_word)))
+    {
+        ALL_WORDS_MATCH_SCORE - length_penalty
+    } else {
+        FUZZY_MATCH_SCORE + fuzzy_score(&query, &candidate)? - length_penalty
+    };
+
+    Some(score)
+}
+
+/// Filters the items matching the query and sorts them by the match quality in descending order.
+/// Items with the same score are sorted alphabetically by their key.
+///
+/// # Arguments
+///
+/// * `items` - The items to search in.
+/// * `query` - The search query.
+/// * `key` - A function returning the text of an item to match the query against.
+pub fn rank_matches<T>(
+    items: impl IntoIterator<Item = T>,
+    query: &str,
+    key: impl Fn(&T) -> String,
+) -> Vec<T> {
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let text = key(&item);
+            match_score(query, &text).map(|score| (Reverse(score), text, item))
+        })
+        .sorted_by(|a, b| Ord::cmp(&(&a.0, &a.1), &(&b.0, &b.1)))
+        .map(|(_, _, item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_text_folds_diacritics_and_special_letters() {
+        assert_eq!(normalize_text("Ødegaard"), "odegaard");
+        assert_eq!(normalize_text("Odegaard"), "odegaard");
+        assert_eq!(normalize_text("Gündoğan"), "gundogan");
+        assert_eq!(normalize_text("Łukasz Fabiański"), "lukasz fabianski");
+        assert_eq!(normalize_text("Straße"), "strasse");
+    }
+
+    #[test]
+    fn normalize_text_collapses_punctuation_and_spaces() {
+        assert_eq!(normalize_text("Saint-Maximin"), "saint maximin");
+        assert_eq!(normalize_text("  Son   Heung-min "), "son heung min");
+        assert_eq!(
+            normalize_text("Brighton & Hove Albion"),
+            "brighton hove albion"
+        );
+        assert_eq!(normalize_text("--"), "");
+    }
+
+    #[test]
+    fn match_score_ignores_case_and_diacritics() {
+        assert_eq!(match_score("odegaard", "Ødegaard"), Some(EXACT_MATCH_SCORE));
+        assert_eq!(match_score("ØDEGAARD", "Odegaard"), Some(EXACT_MATCH_SCORE));
+        assert_eq!(match_score("", "Ødegaard"), Some(0));
+    }
+
+    #[test]
+    fn match_score_tiers_are_ordered() {
+        let scores = [
+            match_score("kane", "Kane"),
+            match_score("kane", "Kanemann"),
+            match_score("kane", "Harry Kane"),
+            match_score("ane", "Harry Kane"),
+            match_score("ha ka", "Harry Kane"),
+            match_score("hrk", "Harry Kane"),
+        ];
+
+        assert!(scores.iter().all(Option::is_some), "{:?}", scores);
+        assert!(
+            scores.windows(2).all(|pair| pair[0] > pair[1]),
+            "{:?}",
+            scores
+        );
+    }
+
+    #[test]
+    fn match_score_prefers_shorter_candidates_in_tier() {
+        assert!(match_score("kane", "Kane Smith") > match_score("kane", "Kanemann Smith"));
+    }
+
+    #[test]
+    fn match_score_of_non_matching_candidate_is_none() {
+        assert_eq!(match_score("salah", "Harry Kane"), None);
+        assert_eq!(match_score("enak", "Kane"), None);
+    }
+
+    #[test]
+    fn rank_matches_sorts_by_score_then_text() {
+        let names = ["Kanemann", "Harry Kane", "Kane", "Martin Ødegaard", "Kane"];
+
+        assert_eq!(
+            rank_matches(names, "kane", |name| name.to_string()),
+            ["Kane", "Kane", "Kanemann", "Harry Kane"]
+        );
+        assert_eq!(
+            rank_matches(names, "odegaard", |name| name.to_string()),
+            ["Martin Ødegaard"]
+        );
+    }
+}
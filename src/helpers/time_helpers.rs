@@ -0,0 +1,87 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Get the number of the seconds since the Unix epoch
+fn unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+// Convert the number of the days since the Unix epoch to the (year, month, day) date of the
+// proleptic Gregorian calendar (see http://howardhinnant.github.io/date_algorithms.html)
+fn civil_date(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Returns today's date (UTC) as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let (year, month, day) = civil_date(unix_seconds() / 86_400);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Returns the current month (UTC) as `YYYY-MM`.
+pub fn current_month() -> String {
+    today()[..7].to_string()
+}
+
+/// Returns the current time (UTC) in the RFC 3339 format, e.g. `2024-05-19T15:30:00Z`.
+pub fn now_utc() -> String {
+    format_utc(unix_seconds())
+}
+
+/// Formats the number of the seconds since the Unix epoch as a UTC time in the RFC 3339 format,
+/// e.g. `2024-05-19T15:30:00Z`.
+pub fn format_utc(unix_seconds: u64) -> String {
+    let (year, month, day) = civil_date(unix_seconds / 86_400);
+    let seconds = unix_seconds % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_date_of_epoch_days() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(59), (1970, 3, 1));
+        // Leap days, the year 2000 is a leap year and 2100 is not
+        assert_eq!(civil_date(11_016), (2000, 2, 29));
+        assert_eq!(civil_date(19_782), (2024, 2, 29));
+        assert_eq!(civil_date(19_783), (2024, 3, 1));
+        assert_eq!(civil_date(47_540), (2100, 2, 28));
+        assert_eq!(civil_date(47_541), (2100, 3, 1));
+        assert_eq!(civil_date(20_088), (2024, 12, 31));
+        assert_eq!(civil_date(20_089), (2025, 1, 1));
+    }
+
+    #[test]
+    fn format_utc_of_unix_seconds() {
+        assert_eq!(format_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc(1_716_132_600), "2024-05-19T15:30:00Z");
+        assert_eq!(format_utc(1_735_689_599), "2024-12-31T23:59:59Z");
+    }
+}
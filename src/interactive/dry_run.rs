@@ -0,0 +1,154 @@
+use std::{error::Error, fmt::Display};
+
+use colorize::AnsiColor;
+use sportradar::{
+    enums::Navigation,
+    helpers::{check_quota_budget, load_season_snapshots},
+    models::{AppState, SeasonData},
+    services::{ApiService, AppStateService},
+};
+
+use super::prompts::{prompt_select, prompt_select_or_back};
+
+/// The season to plan the requests of: one from the local cache, or another one, which needs the
+/// competitions and the seasons to be fetched.
+enum PlannedSeason {
+    Cached(Box<SeasonData>),
+    Other,
+}
+
+impl Display for PlannedSeason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlannedSeason::Cached(snapshot) => {
+                write!(f, "{} - {}", snapshot.competition, snapshot.season)
+            }
+            PlannedSeason::Other => {
+                write!(f, "Another season (fetches the competitions and seasons)")
+            }
+        }
+    }
+}
+
+/// Lets the user select a season (from the local cache where possible) and prints the API
+/// requests loading the season would send, with the key redacted, and how long they would take
+/// at the rate limit of the API key. The season data itself is never fetched.
+pub async fn dry_run<HttpClient: ApiService>(
+    app: &mut AppState<HttpClient>,
+) -> Result<(), Box<dyn Error>> {
+    let sports = app.on_start().await?;
+    let sport = prompt_select("Select a sport:", sports.iter().cloned().collect(), 15)?;
+    app.set_selected_sport(&sport);
+
+    // The API requests sent to find the competition and the season
+    let mut sent = 0;
+    loop {
+        let options = load_season_snapshots::<SeasonData>(&app.api_config().cache_dir)
+            .into_iter()
+            .map(|snapshot| PlannedSeason::Cached(Box::new(snapshot)))
+            .chain([PlannedSeason::Other])
+            .collect();
+        match prompt_select("Select a season to plan:", options, 15)? {
+            PlannedSeason::Cached(snapshot) => {
+                app.restore_season_snapshot(*snapshot);
+                break;
+            }
+            // Going back from the competitions returns to the seasons to plan
+            PlannedSeason::Other => {
+                if select_season(app, &mut sent).await? {
+                    break;
+                }
+            }
+        }
+    }
+    if sent > 0 {
+        println!(
+            "{}",
+            format!(
+                "{} API {} sent to find the competition and the season",
+                sent,
+                if sent == 1 {
+                    "request was"
+                } else {
+                    "requests were"
+                }
+            )
+            .yellow()
+        );
+    }
+
+    print_plan(app)
+}
+
+// Fetch the competitions and the seasons (each of them once) and let the user select one, counting
+// the sent requests. Going back from the seasons returns to the competitions, going back from the
+// competitions returns `false`
+async fn select_season<HttpClient: ApiService>(
+    app: &mut AppState<HttpClient>,
+    sent: &mut usize,
+) -> Result<bool, Box<dyn Error>> {
+    if app.competitions().is_empty() {
+        app.on_sport_select().await?;
+        *sent += 1;
+    }
+
+    loop {
+        let competitions = app.competitions().iter().cloned().collect();
+        let Navigation::Selected(competition) =
+            prompt_select_or_back("Select a competition:", competitions, 15)?
+        else {
+            return Ok(false);
+        };
+        if app.selected_competition().as_ref() != Some(&competition) {
+            app.reset_selected_competition();
+            app.set_selected_competition(&competition);
+        }
+
+        if app.seasons().is_empty() {
+            app.on_competition_select().await?;
+            *sent += 1;
+        }
+        if let Navigation::Selected(season) =
+            prompt_select_or_back("Select a season:", app.seasons().to_vec(), 15)?
+        {
+            app.reset_selected_season();
+            app.set_selected_season(season);
+            return Ok(true);
+        }
+    }
+}
+
+fn print_plan<HttpClient: ApiService>(app: &AppState<HttpClient>) -> Result<(), Box<dyn Error>> {
+    let plan = app.season_request_plan();
+    let config = app.api_config();
+
+    println!("{}", "Request plan:".green().bold());
+    for (index, request) in plan.requests.iter().enumerate() {
+        println!("{:>3}. {:<30} {}", index + 1, request.endpoint, request.url);
+    }
+    if !plan.is_complete {
+        println!(
+            "  +  one season_competitor_statistics request per competitor, known after the schedules are fetched"
+        );
+    }
+
+    let estimate = format!(
+        "{}{} requests, about {}s at {} requests per second (the retries not included)",
+        plan.requests.len(),
+        if plan.is_complete { "" } else { "+" },
+        plan.estimated_duration(config.rate_limit)
+            .as_secs_f64()
+            .ceil(),
+        config.rate_limit
+    );
+    println!("{}", estimate.yellow().bold());
+
+    if let Some(budget) = config.monthly_budget {
+        match check_quota_budget(config, plan.requests.len()) {
+            Ok(()) => println!("The plan fits in the monthly budget of {} requests", budget),
+            Err(err) => println!("{}", err.red()),
+        }
+    }
+
+    Ok(())
+}
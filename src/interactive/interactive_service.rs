@@ -0,0 +1,329 @@
+use std::{error::Error, sync::Arc};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use sportradar::{
+    enums::{MenuAction, Navigation, NextAction, Players, SearchHit, Step},
+    services::{
+        ApiService, AppStateService, BaseInfoService, CompetitorsService, ConstructService,
+        CountryService, PlayerSeasonStatisticsService, PlayerService, PlayerStatisticsService,
+        SeasonService, SportApiService, SportEventStatusService,
+    },
+};
+
+use super::{
+    leaderboard_table::{LeaderboardRow, TableStyle},
+    progress_bar::ProgressBar,
+    prompts::{
+        print_farewell, prompt_fuzzy_select, prompt_number_or_back, prompt_select,
+        prompt_select_or_back,
+    },
+};
+
+/// The `InteractiveService` trait extends [`AppStateService`] with the interactive wizard of the CLI.
+///
+/// It has the same type parameters and bounds as [`AppStateService`] and is implemented for every type
+/// implementing it. All the fetching and computations are delegated to [`AppStateService`], this trait
+/// only prompts the user and prints the results.
+pub trait InteractiveService<
+    HttpClient,
+    Sport,
+    Competition,
+    Season,
+    SportEvent,
+    Competitor,
+    Player,
+    PlayerStatistics,
+>:
+    AppStateService<
+    HttpClient,
+    Sport,
+    Competition,
+    Season,
+    SportEvent,
+    Competitor,
+    Player,
+    PlayerStatistics,
+> where
+    HttpClient: ApiService,
+    Sport: BaseInfoService + SportApiService,
+    Competition: BaseInfoService + CountryService,
+    Season: BaseInfoService + SeasonService,
+    SportEvent: CompetitorsService<Competitor>
+        + SportEventStatusService
+        + for<'de> Deserialize<'de>
+        + Serialize
+        + Clone
+        + std::cmp::Eq
+        + std::hash::Hash,
+    Competitor: BaseInfoService + ConstructService,
+    Player: BaseInfoService
+        + PlayerStatisticsService
+        + PlayerSeasonStatisticsService
+        + PlayerService<PlayerStatistics, Competitor>,
+    PlayerStatistics: PlayerStatisticsService,
+{
+    /// Lets the user find a player or a team of the selected season by typing a part of the name
+    /// and prints the season statistics of the found player or of all the players of the found team.
+    fn prompt_search(&self) -> Result<(), Box<dyn Error>> {
+        let options: Vec<SearchHit<Player, Competitor>> = self
+            .players()
+            .values()
+            .cloned()
+            .map(SearchHit::Player)
+            .chain(self.competitors().iter().cloned().map(SearchHit::Team))
+            .sorted_by_key(|hit| hit.to_string())
+            .collect();
+
+        // The hits are matched by the name only, as in the `search` command
+        match prompt_fuzzy_select("Type a player or team name:", options, 15, SearchHit::name)? {
+            None => {}
+            Some(SearchHit::Player(player)) => println!(
+                "{} - {} goals, {} assists",
+                player,
+                player.season_goals(),
+                player.season_assists()
+            ),
+            Some(SearchHit::Team(team)) => {
+                let team_players = self
+                    .players()
+                    .values()
+                    .filter(|player| player.team() == &team)
+                    .sorted_by_key(|player| player.name());
+                for player in team_players {
+                    println!(
+                        "{} - {} goals, {} assists",
+                        player.name(),
+                        player.season_goals(),
+                        player.season_assists()
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints the leaderboard of the selected player statistics as a table in the style (see
+    /// [`TableStyle`]).
+    fn print_results(&self, style: &TableStyle) -> Result<(), Box<dyn Error>> {
+        let Some(statistics) = self.selected_player_statistics() else {
+            return Ok(());
+        };
+
+        let rows: Vec<LeaderboardRow> = self
+            .get_top_players(statistics, self.limit().unwrap_or_default())
+            .iter()
+            .enumerate()
+            .map(|(index, player)| LeaderboardRow {
+                rank: index + 1,
+                player: player.name(),
+                team: player.team().name(),
+                value: statistics.season_value(player),
+            })
+            .collect();
+        println!(
+            "{}",
+            style.render_leaderboard(statistics.column_title(), &rows)
+        );
+
+        Ok(())
+    }
+
+    /// Runs the application, allowing the user to select a sport, competition, season, and player
+    /// statistics to view.
+    ///
+    /// This method is the main entry point for the application. It guides the user step by step
+    /// (see [`Step`]) through the process of selecting a sport, competition, season, and player
+    /// statistics to view. Every step except the first one lets the user go back to the previous step.
+    ///
+    /// Once all selections are made, the method displays the top goal scorers or top assistants for the
+    /// selected season, based on the user's choice. The number of players displayed is determined by the
+    /// limit set by the user.
+    ///
+    /// After the results are displayed, the user chooses what to change next (see [`NextAction`]) or
+    /// quits. The data already fetched is kept as long as it is still valid: e.g. changing the statistics
+    /// or the limit does not fetch the season again, and changing the season reuses the fetched seasons.
+    ///
+    /// While a season is fetched, a progress bar is shown on stderr (unless stderr is not a terminal).
+    /// The leaderboards are printed as tables in the given style.
+    async fn run(&mut self, style: TableStyle) -> Result<(), Box<dyn Error>> {
+        if let Some(progress_bar) = ProgressBar::for_stderr() {
+            self.set_progress_observer(Arc::new(progress_bar));
+        }
+        let mut step = Step::Sport;
+
+        loop {
+            step = match step {
+                Step::Sport => {
+                    let sports = self.on_start().await?; // fetch sports
+                    let options: Vec<Sport> = sports.into_iter().collect(); // convert to a select options
+                    let sport = prompt_select("Select a sport:", options, 15)?;
+
+                    // Another sport invalidates all the fetched data
+                    if self.selected_sport().as_ref() != Some(&sport) {
+                        self.reset();
+                        self.set_selected_sport(&sport);
+                    }
+                    Step::Competition
+                }
+
+                Step::Competition => {
+                    if self.competitions().is_empty() {
+                        self.on_sport_select().await?; // fetch competitions and save them to the state
+                    }
+                    let options: Vec<Competition> = self.competitions().iter().cloned().collect();
+
+                    match prompt_select_or_back("Select a competition:", options, 15)? {
+                        Navigation::Back => Step::Sport,
+                        Navigation::Selected(competition) => {
+                            if self.selected_competition().as_ref() != Some(&competition) {
+                                self.reset_selected_competition();
+                                self.set_selected_competition(&competition);
+                            }
+                            Step::Season
+                        }
+                    }
+                }
+
+                Step::Season => {
+                    if self.seasons().is_empty() {
+                        self.on_competition_select().await?; // fetch seasons and save them to the state
+                    }
+                    let options: Vec<Season> = self.seasons().to_vec();
+
+                    match prompt_select_or_back("Select a season:", options, 15)? {
+                        Navigation::Back => Step::Competition,
+                        Navigation::Selected(season) => {
+                            if self.selected_season().as_ref() != Some(&season) {
+                                self.reset_selected_season();
+                                self.set_selected_season(season);
+                            }
+                            Step::PlayerStatistics
+                        }
+                    }
+                }
+
+                Step::PlayerStatistics => {
+                    // Fetch the season data only if it was not fetched for the selected season yet
+                    if self.players().is_empty() {
+                        self.on_season_select().await?; // fetch players and save them to the state
+                        if let Err(err) = self.save_season_snapshot() {
+                            tracing::warn!(
+                                error = %err,
+                                "Failed to save the season to the local cache"
+                            );
+                        }
+                    }
+                    let options: Vec<MenuAction> = vec![
+                        MenuAction::Leaderboard(Players::TopGoalScorers),
+                        MenuAction::Leaderboard(Players::TopAssistants),
+                        MenuAction::Search,
+                    ]; // player statistics options
+
+                    match prompt_select_or_back("What statistics do you want to see?", options, 15)?
+                    {
+                        Navigation::Back => Step::Season,
+                        // The search shows all the matches, so the limit is not needed
+                        Navigation::Selected(MenuAction::Search) => {
+                            self.prompt_search()?;
+                            Step::NextAction
+                        }
+                        Navigation::Selected(MenuAction::Leaderboard(player_statistics)) => {
+                            self.set_selected_player_statistics(player_statistics);
+                            match self.limit() {
+                                Some(_) => Step::Results,
+                                None => Step::Limit,
+                            }
+                        }
+                    }
+                }
+
+                Step::Limit => match prompt_number_or_back("How many players do you want to see?")?
+                {
+                    Navigation::Back => Step::PlayerStatistics,
+                    Navigation::Selected(limit) => {
+                        self.set_limit(limit);
+                        Step::Results
+                    }
+                },
+
+                Step::Results => {
+                    self.print_results(&style)?;
+                    Step::NextAction
+                }
+
+                Step::NextAction => {
+                    let options = vec![
+                        NextAction::ChangePlayerStatistics,
+                        NextAction::ChangeLimit,
+                        NextAction::ChangeSeason,
+                        NextAction::ChangeCompetition,
+                        NextAction::ChangeSport,
+                        NextAction::Quit,
+                    ];
+
+                    match prompt_select("What do you want to do next?", options, 15)?.step() {
+                        Some(step) => step,
+                        None => {
+                            print_farewell();
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<
+        T,
+        HttpClient,
+        Sport,
+        Competition,
+        Season,
+        SportEvent,
+        Competitor,
+        Player,
+        PlayerStatistics,
+    >
+    InteractiveService<
+        HttpClient,
+        Sport,
+        Competition,
+        Season,
+        SportEvent,
+        Competitor,
+        Player,
+        PlayerStatistics,
+    > for T
+where
+    T: AppStateService<
+        HttpClient,
+        Sport,
+        Competition,
+        Season,
+        SportEvent,
+        Competitor,
+        Player,
+        PlayerStatistics,
+    >,
+    HttpClient: ApiService,
+    Sport: BaseInfoService + SportApiService,
+    Competition: BaseInfoService + CountryService,
+    Season: BaseInfoService + SeasonService,
+    SportEvent: CompetitorsService<Competitor>
+        + SportEventStatusService
+        + for<'de> Deserialize<'de>
+        + Serialize
+        + Clone
+        + std::cmp::Eq
+        + std::hash::Hash,
+    Competitor: BaseInfoService + ConstructService,
+    Player: BaseInfoService
+        + PlayerStatisticsService
+        + PlayerSeasonStatisticsService
+        + PlayerService<PlayerStatistics, Competitor>,
+    PlayerStatistics: PlayerStatisticsService,
+{
+}
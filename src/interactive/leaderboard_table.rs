@@ -0,0 +1,284 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use clap::ValueEnum;
+use colorize::AnsiColor;
+use ratatui::crossterm::terminal;
+
+// The width of the output which is not a terminal, unless the `COLUMNS` variable sets it
+const DEFAULT_WIDTH: usize = 80;
+// The widest bar chart, a longer bar does not make the differences easier to see
+const MAX_BAR_WIDTH: usize = 40;
+const MIN_BAR_WIDTH: usize = 5;
+const MIN_NAME_WIDTH: usize = 6;
+const COLUMN_GAP: &str = "  ";
+// The blocks drawing the end of a bar in eighths of a character
+const PARTIAL_BLOCKS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// The colours of the leaderboard tables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorTheme {
+    /// Green headers and bars, yellow values
+    #[default]
+    Classic,
+    /// Cyan headers and values, blue bars
+    Ocean,
+    /// No colours, only the bold headers
+    Mono,
+}
+
+impl ColorTheme {
+    // Get the colour functions of the header, the values, and the bars
+    fn colors(&self) -> [fn(String) -> String; 3] {
+        match self {
+            ColorTheme::Classic => [
+                |text: String| text.green().bold(),
+                |text: String| text.yellow(),
+                |text: String| text.green(),
+            ],
+            ColorTheme::Ocean => [
+                |text: String| text.cyan().bold(),
+                |text: String| text.cyan(),
+                |text: String| text.blue(),
+            ],
+            ColorTheme::Mono => [|text: String| text.bold(), |text| text, |text| text],
+        }
+    }
+}
+
+/// A row of a leaderboard table.
+#[derive(Debug, Clone)]
+pub struct LeaderboardRow {
+    pub rank: usize,
+    pub player: String,
+    pub team: String,
+    pub value: u8,
+}
+
+/// How the leaderboard tables are printed: the colour theme, whether to draw the bar charts of the
+/// values, and the width of the output.
+#[derive(Debug, Clone)]
+pub struct TableStyle {
+    pub theme: ColorTheme,
+    pub bars: bool,
+    /// Whether to use the colours of the theme.
+    pub color: bool,
+    /// The width of the output in characters.
+    pub width: usize,
+}
+
+impl TableStyle {
+    /// Returns the style of the tables printed to stdout. The colours are used only if stdout is a
+    /// terminal and the `NO_COLOR` variable is not set (see https://no-color.org), and the tables
+    /// fit the width of the terminal (or `COLUMNS`, 80 characters by default, if stdout is not a
+    /// terminal).
+    pub fn for_stdout(theme: ColorTheme, bars: bool) -> Self {
+        let is_terminal = io::stdout().is_terminal();
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let terminal_width = is_terminal
+            .then(|| terminal::size().ok())
+            .flatten()
+            .map(|(columns, _)| usize::from(columns))
+            // A pseudo terminal may report no size
+            .filter(|columns| *columns > 0);
+        let width = terminal_width
+            .or_else(|| env::var("COLUMNS").ok()?.parse().ok())
+            .unwrap_or(DEFAULT_WIDTH);
+
+        Self {
+            theme,
+            bars,
+            color: is_terminal && !no_color,
+            width,
+        }
+    }
+
+    /// Renders the leaderboard as a table with the rank, player, team, and value columns (and the
+    /// bar chart of the values if enabled), one line per row. The player and team names are
+    /// shortened if the table would not fit the width.
+    pub fn render_leaderboard(&self, value_header: &str, rows: &[LeaderboardRow]) -> String {
+        let [header_color, value_color, bar_color] = if self.color {
+            self.theme.colors()
+        } else {
+            [|text| text, |text| text, |text| text]
+        };
+
+        let rank_width = column_width("#", rows.iter().map(|row| row.rank.to_string()));
+        let value_width = column_width(value_header, rows.iter().map(|row| row.value.to_string()));
+        let mut player_width = column_width("Player", rows.iter().map(|row| row.player.clone()));
+        let mut team_width = column_width("Team", rows.iter().map(|row| row.team.clone()));
+
+        // The room left for the names (and the bars) after the fixed-width columns and the gaps
+        let gaps = COLUMN_GAP.len() * if self.bars { 4 } else { 3 };
+        let available = self.width.saturating_sub(rank_width + value_width + gaps);
+        let min_bar_width = if self.bars { MIN_BAR_WIDTH } else { 0 };
+        let names_width = available.saturating_sub(min_bar_width);
+        if player_width + team_width > names_width {
+            // The team names give way first, they are repeated and easier to recognize
+            team_width = team_width.min((names_width * 2 / 5).max(MIN_NAME_WIDTH));
+            player_width =
+                player_width.min(names_width.saturating_sub(team_width).max(MIN_NAME_WIDTH));
+        }
+        let bar_width = available
+            .saturating_sub(player_width + team_width)
+            .clamp(min_bar_width, MAX_BAR_WIDTH.max(min_bar_width));
+        let max_value = rows.iter().map(|row| row.value).max().unwrap_or_default();
+
+        let mut header = format!(
+            "{:>rank_width$}{COLUMN_GAP}{:<player_width$}{COLUMN_GAP}{:<team_width$}{COLUMN_GAP}{:>value_width$}",
+            "#", "Player", "Team", value_header
+        );
+        if self.bars {
+            header = format!("{header}{COLUMN_GAP}{:bar_width$}", "");
+        }
+        let mut lines = vec![header_color(header.trim_end().to_string())];
+
+        for row in rows {
+            let mut line = format!(
+                "{:>rank_width$}{COLUMN_GAP}{:<player_width$}{COLUMN_GAP}{:<team_width$}{COLUMN_GAP}{}",
+                row.rank,
+                truncate(&row.player, player_width),
+                truncate(&row.team, team_width),
+                value_color(format!("{:>value_width$}", row.value)),
+            );
+            if self.bars {
+                line.push_str(COLUMN_GAP);
+                line.push_str(&bar_color(bar(row.value, max_value, bar_width)));
+            }
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+}
+
+// Get the width of a column fitting the header and all the values
+fn column_width(header: &str, values: impl Iterator<Item = String>) -> usize {
+    values
+        .map(|value| value.chars().count())
+        .chain([header.chars().count()])
+        .max()
+        .unwrap_or_default()
+}
+
+// Shorten the text to the width, marking the cut with an ellipsis
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+// Draw the bar of the value scaled to the largest value, with the precision of an eighth of a
+// character
+fn bar(value: u8, max_value: u8, width: usize) -> String {
+    if max_value == 0 {
+        return String::new();
+    }
+    let eighths = usize::from(value) * width * 8 / usize::from(max_value);
+    let mut bar = "█".repeat(eighths / 8);
+    let partial = eighths % 8;
+    if partial > 0 {
+        bar.push(PARTIAL_BLOCKS[partial]);
+    }
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(bars: bool, width: usize) -> TableStyle {
+        TableStyle {
+            theme: ColorTheme::Classic,
+            bars,
+            color: false,
+            width,
+        }
+    }
+
+    fn rows() -> Vec<LeaderboardRow> {
+        [
+            ("Erling Haaland", "Manchester City", 27),
+            ("Cole Palmer", "Chelsea FC", 22),
+            ("Martin Ødegaard", "Arsenal FC", 8),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (player, team, value))| LeaderboardRow {
+            rank: index + 1,
+            player: player.to_string(),
+            team: team.to_string(),
+            value,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn truncate_marks_the_cut_with_ellipsis() {
+        assert_eq!(truncate("Ødegaard", 8), "Ødegaard");
+        assert_eq!(truncate("Ødegaard", 5), "Ødeg…");
+        assert_eq!(truncate("Ødegaard", 1), "…");
+        assert_eq!(truncate("", 0), "");
+    }
+
+    #[test]
+    fn bar_is_scaled_to_largest_value() {
+        assert_eq!(bar(8, 8, 4), "████");
+        assert_eq!(bar(4, 8, 4), "██");
+        assert_eq!(bar(1, 8, 4), "▌");
+        assert_eq!(bar(3, 8, 3), "█▏");
+        assert_eq!(bar(0, 8, 4), "");
+        assert_eq!(bar(0, 0, 4), "");
+    }
+
+    #[test]
+    fn render_leaderboard_aligns_columns() {
+        let table = style(false, 80).render_leaderboard("Goals", &rows());
+
+        assert_eq!(
+            table,
+            [
+                "#  Player           Team             Goals",
+                "1  Erling Haaland   Manchester City     27",
+                "2  Cole Palmer      Chelsea FC          22",
+                "3  Martin Ødegaard  Arsenal FC           8",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn render_leaderboard_shortens_names_to_fit_width() {
+        let table = style(false, 30).render_leaderboard("Goals", &rows());
+
+        for line in table.lines() {
+            assert!(line.chars().count() <= 30, "{:?}", line);
+        }
+        assert!(table.contains("Manche…"), "{}", table);
+    }
+
+    #[test]
+    fn render_leaderboard_draws_bars() {
+        let table = style(true, 60).render_leaderboard("Goals", &rows());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0].trim_end(), lines[0]);
+        assert!(lines[1].ends_with(&"█".repeat(MIN_BAR_WIDTH)), "{}", table);
+        for line in &lines {
+            assert!(line.chars().count() <= 60, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn render_leaderboard_without_rows_has_only_header() {
+        assert_eq!(
+            style(true, 80).render_leaderboard("Assists", &[]),
+            "#  Player  Team  Assists"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+mod dry_run;
+mod interactive_service;
+mod leaderboard_table;
+mod progress_bar;
+mod prompts;
+
+pub use dry_run::dry_run;
+pub use interactive_service::InteractiveService;
+pub use leaderboard_table::{ColorTheme, LeaderboardRow, TableStyle};
+pub use progress_bar::ProgressBar;
@@ -0,0 +1,47 @@
+use std::io::{self, IsTerminal, Write};
+
+use sportradar::{models::FetchProgress, services::FetchProgressService};
+
+const BAR_WIDTH: usize = 30;
+
+/// A one-line progress bar of the competitor statistics fetch, redrawn in place on stderr.
+#[derive(Debug, Default)]
+pub struct ProgressBar;
+
+impl ProgressBar {
+    /// Returns the progress bar if stderr is a terminal, so that the redirected output stays clean.
+    pub fn for_stderr() -> Option<Self> {
+        io::stderr().is_terminal().then_some(Self)
+    }
+}
+
+impl FetchProgressService for ProgressBar {
+    fn on_progress(&self, progress: &FetchProgress) {
+        let filled = (progress.completed * BAR_WIDTH)
+            .checked_div(progress.total)
+            .unwrap_or(BAR_WIDTH);
+        let mut stderr = io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r\x1b[2KFetching competitor statistics [{}{}] {}/{} ({} failed, {} retries)",
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled),
+            progress.completed,
+            progress.total,
+            progress.failed,
+            progress.retries
+        );
+        let _ = stderr.flush();
+    }
+
+    fn on_start(&self, progress: &FetchProgress) {
+        self.on_progress(progress);
+    }
+
+    // Clear the bar, the results are printed right after it
+    fn on_finish(&self, _progress: &FetchProgress) {
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+use colorize::AnsiColor;
+use inquire::{CustomType, Select};
+
+use sportradar::{enums::Navigation, helpers::match_score};
+
+const BACK_HELP_MESSAGE: &str = "↑↓ to move, enter to select, type to filter, esc to go back";
+
+pub fn prompt_select<T: Display>(
+    message: impl Into<String>,
+    options: Vec<T>,
+    page_size: usize,
+) -> Result<T, inquire::InquireError> {
+    let message = message.into().green().bold();
+    Select::new(message.as_str(), options)
+        .with_page_size(page_size)
+        .prompt()
+}
+
+/// Prompts the user to select an option or to go back to the previous step. Going back is offered
+/// as the last option and is also chosen by pressing Esc.
+pub fn prompt_select_or_back<T: Display>(
+    message: impl Into<String>,
+    options: Vec<T>,
+    page_size: usize,
+) -> Result<Navigation<T>, inquire::InquireError> {
+    let message = message.into().green().bold();
+    let options = options
+        .into_iter()
+        .map(Navigation::Selected)
+        .chain([Navigation::Back])
+        .collect();
+
+    let choice = Select::new(message.as_str(), options)
+        .with_page_size(page_size)
+        .with_help_message(BACK_HELP_MESSAGE)
+        .prompt_skippable()?;
+
+    Ok(choice.unwrap_or(Navigation::Back))
+}
+
+/// Prompts the user to select an option, filtering and ranking the options by the fuzzy match
+/// of the typed text against the text returned by `key` for each option (see [`match_score`]),
+/// the same way as [`sportradar::helpers::rank_matches`]. Unlike [`prompt_select`], the typed
+/// text ignores case and diacritics.
+///
+/// Returns `None` if the user cancels the prompt by pressing Esc.
+pub fn prompt_fuzzy_select<T: Display>(
+    message: impl Into<String>,
+    options: Vec<T>,
+    page_size: usize,
+    key: impl Fn(&T) -> String,
+) -> Result<Option<T>, inquire::InquireError> {
+    let message = message.into().green().bold();
+    let scorer = |input: &str, option: &T, _string_value: &str, _index: usize| {
+        match_score(input, &key(option))
+    };
+
+    Select::new(message.as_str(), options)
+        .with_page_size(page_size)
+        .with_scorer(&scorer)
+        .with_help_message("type to search, enter to select, esc to go back")
+        .prompt_skippable()
+}
+
+/// Prompts the user to enter a non-zero number or to go back to the previous step by pressing Esc.
+pub fn prompt_number_or_back(
+    message: impl Into<String>,
+) -> Result<Navigation<usize>, inquire::InquireError> {
+    let message = message.into().green().bold();
+    let number = CustomType::<usize>::new(message.as_str())
+        .with_error_message("Please enter a valid non-zero number")
+        .with_placeholder("Enter a number")
+        .with_help_message("esc to go back")
+        .with_parser(&|input: &str| {
+            let number = input.parse::<usize>();
+
+            match number {
+                Ok(number) if number > 0 => Ok(number),
+                _ => Err(()),
+            }
+        })
+        .prompt_skippable()?;
+
+    Ok(number.map_or(Navigation::Back, Navigation::Selected))
+}
+
+pub fn print_farewell() {
+    println!(
+        "\n{}",
+        "Thank you for using SportRadar CLI! Bye!".yellow().bold()
+    );
+}
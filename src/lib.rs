@@ -0,0 +1,44 @@
+//! A client library for the [Sportradar Soccer API](https://developer.sportradar.com/soccer/reference/soccer-api-overview)
+//! and the engine of the SportRadar CLI.
+//!
+//! The quickest way to get the data is [`SportradarClient`]:
+//!
+//! ```no_run
+//! use sportradar::{config::SportApi, enums::Players, top_players, SportradarClient};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let soccer = SportApi {
+//!     allowed_competitions: Some(vec!["Premier League".to_string()]),
+//!     ..SportApi::soccer()
+//! };
+//! let client = SportradarClient::builder()
+//!     .api_key("YOUR_API_KEY")
+//!     .sports(vec![soccer])
+//!     .build();
+//!
+//! let competitions = client.fetch_competitions().await?;
+//! let seasons = client.fetch_seasons(&competitions[0]).await?;
+//! let players = client.fetch_season_players(&seasons[0]).await?;
+//!
+//! for player in top_players(&players, Players::TopGoalScorers, 10) {
+//!     println!("{}", player);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The whole fetch pipeline is implemented by the [`services::AppStateService`] trait, which is
+//! generic over all the [`models`] and the HTTP client ([`services::ApiService`]), so any part of it
+//! can be replaced. [`models::AppState`] is the implementation used by the CLI.
+
+pub mod api_responses;
+pub mod client;
+pub mod config;
+pub mod enums;
+pub mod helpers;
+pub mod models;
+pub mod services;
+
+pub use client::{SportradarClient, SportradarClientBuilder};
+pub use config::{ApiConfig, SportApi};
+pub use helpers::top_players;
@@ -1,37 +1,153 @@
-use std::error::Error;
+use std::{error::Error, fs, time::Duration};
 
+use clap::Parser;
 use dotenvy::dotenv;
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
+use sportradar::{
+    client::{RecordingClient, ReplayClient},
+    helpers::{diagnostics_report, flush_quota_usage},
+    services::ApiService,
+    ApiConfig, SportradarClient, SportradarClientBuilder,
+};
+use tracing::Level;
 
-use self::{models::AppState, services::AppStateService};
+use self::{
+    commands::{Cli, Command},
+    interactive::{InteractiveService, TableStyle},
+};
 
-mod api_responses;
-mod enums;
-mod helpers;
-mod models;
-mod services;
+mod commands;
+mod export;
+mod interactive;
+mod mock;
+mod report;
+mod server;
+mod tui;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let mut cli = Cli::parse();
+
     // Load environment variables from .env file
     dotenv().ok();
 
-    // ===== APP STATE SETUP =====
+    // The server logs the fetched seasons by default, the other commands only the failures
+    let log_level = match cli.command {
+        Some(Command::Serve(_)) => Level::INFO,
+        _ => Level::WARN,
+    };
+    commands::init_logging(cli.verbose, cli.log_format, log_level);
+    let table_style = TableStyle::for_stdout(cli.theme, cli.bars);
 
-    // Create a new HTTP client with retry middleware to handle transient errors
-    // Retry up to 3 times with increasing intervals between attempts.
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(3);
-    let client = ClientBuilder::new(reqwest::Client::new())
-        .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-        .build();
+    // The search, the leaders, the export, the report and the charts work with the local cache only, the mock server with
+    // the fixtures only, and the quota command with the local API usage only, they do not need the
+    // API settings
+    let command = match cli.command.take() {
+        Some(Command::Search(args)) => return commands::search(&args),
+        Some(Command::Mock(args)) => return mock::run_mock_server(args).await,
+        Some(Command::Quota(args)) => return commands::show_quota(&args),
+        Some(Command::Export(args)) => return export::run_export(&args),
+        Some(Command::Leaders(args)) => return commands::leaders(&args, &table_style),
+        Some(Command::Report(args)) => return report::run_report(&args),
+        Some(Command::Charts(args)) => return report::run_charts(&args),
+        command => command,
+    };
 
-    // Create an instance of the app state
-    let mut app: AppState<ClientWithMiddleware> = AppState::new(client);
+    // ===== APP STATE SETUP =====
+
+    // Create a new client with the settings from the environment variables. Its HTTP client retries
+    // the transient errors up to 3 times with increasing intervals between attempts.
+    let client = SportradarClientBuilder::from_env()?.max_retries(3).build();
+    let client = match &cli.sport {
+        Some(sport) => client.for_sport(sport)?,
+        None => client,
+    };
 
     // ===== RUN THE APP =====
 
-    app.run().await?;
+    let quota_file = client.config().quota_file.clone();
+
+    let result = match (command, cli.record, cli.replay) {
+        (Some(_), _, _) if cli.dry_run => {
+            Err("The --dry-run option is supported in the interactive mode only".into())
+        }
+        (Some(Command::Serve(args)), None, None) => {
+            server::run_server(client, args.address, Duration::from_secs(args.cache_ttl)).await
+        }
+        (Some(Command::Serve(_)), _, _) => {
+            Err("The server does not support the --record and --replay options".into())
+        }
+        (command, Some(fixture_dir), _) => {
+            let http_client = RecordingClient::new(client.http_client().clone(), fixture_dir);
+            run(
+                SportradarClient::new(http_client, client.config().clone()),
+                command,
+                cli.dry_run,
+                table_style,
+            )
+            .await
+        }
+        (command, None, Some(fixture_dir)) => {
+            let http_client = ReplayClient::new(fixture_dir);
+            // The replayed responses do not use up the API quota
+            let config = ApiConfig {
+                monthly_budget: None,
+                ..client.config().clone()
+            };
+            run(
+                SportradarClient::new(http_client, config),
+                command,
+                cli.dry_run,
+                table_style,
+            )
+            .await
+        }
+        (command, None, None) => run(client, command, cli.dry_run, table_style).await,
+    };
+    flush_quota_usage(&quota_file);
+
+    // The report is written even if the app failed, the failure may be caused by the schema drift
+    if let Some(path) = cli.diagnostics {
+        let report = diagnostics_report();
+        fs::write(&path, serde_json::to_vec_pretty(&report)?)?;
+        eprintln!(
+            "Diagnostics of {} API responses ({} skipped items) written to {}",
+            report.responses.len(),
+            report.skipped_items_count(),
+            path.display()
+        );
+    }
+
+    result
+}
+
+// Run the interactive mode (or its dry run), the dashboard, or the raw request on top of any HTTP
+// client
+async fn run<HttpClient: ApiService + Clone + Sync>(
+    client: SportradarClient<HttpClient>,
+    command: Option<Command>,
+    dry_run: bool,
+    table_style: TableStyle,
+) -> Result<(), Box<dyn Error>> {
+    // Create an instance of the app state
+    let mut app = client.app_state();
 
-    Ok(())
+    match command {
+        None if dry_run => interactive::dry_run(&mut app).await,
+        None => app.run(table_style).await,
+        Some(Command::Tui) => tui::run_dashboard(&mut app).await,
+        Some(Command::Raw(args)) => commands::raw(&client, &args).await,
+        Some(Command::Archive(args)) => commands::archive(&client, &args).await,
+        Some(
+            Command::Search(_)
+            | Command::Serve(_)
+            | Command::Mock(_)
+            | Command::Quota(_)
+            | Command::Export(_)
+            | Command::Leaders(_)
+            | Command::Report(_)
+            | Command::Charts(_),
+        ) => {
+            unreachable!("The local cache commands, the servers and the quota are run before")
+        }
+    }
 }
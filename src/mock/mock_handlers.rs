@@ -0,0 +1,101 @@
+use std::{fs, sync::Arc, time::Duration};
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use sportradar::helpers::{fixture_path, scrub_api_key};
+
+use super::mock_state::{Faults, MockState};
+
+type SharedState = State<Arc<MockState>>;
+
+// Sportradar-shaped error body
+fn message_response(status: StatusCode, message: impl Into<String>) -> Response {
+    let message = message.into();
+    (status, Json(serde_json::json!({ "message": message }))).into_response()
+}
+
+/// Serves the fixture of the requested URL, injecting the configured faults.
+pub async fn fixture(State(state): SharedState, uri: Uri) -> Response {
+    let path = scrub_api_key(&format!("http://mock{}", uri));
+    let path = path.trim_start_matches("http://mock").to_string();
+    let faults = state.take_faults(&path);
+
+    if let Some(faults) = &faults {
+        tokio::time::sleep(Duration::from_millis(faults.delay_ms)).await;
+    }
+
+    let response = match faults {
+        Some(Faults {
+            status: Some(status),
+            ..
+        }) => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let mut response = message_response(status, "Injected fault");
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, header::HeaderValue::from_static("1"));
+            }
+            response
+        }
+        faults => {
+            let malformed = faults.is_some_and(|faults| faults.malformed);
+            read_fixture(&state, &uri, malformed)
+        }
+    };
+
+    state.log_request(&path, response.status().as_u16());
+    response
+}
+
+fn read_fixture(state: &MockState, uri: &Uri, malformed: bool) -> Response {
+    let fixture = fixture_path(&state.fixture_dir, &format!("http://mock{}", uri));
+    let Ok(mut body) = fs::read_to_string(&fixture) else {
+        return message_response(
+            StatusCode::NOT_FOUND,
+            format!("No fixture {}", fixture.display()),
+        );
+    };
+
+    // Cut the body in the middle, so that it cannot be parsed
+    if malformed {
+        let middle = (0..=body.len() / 2)
+            .rev()
+            .find(|index| body.is_char_boundary(*index))
+            .unwrap_or_default();
+        body.truncate(middle);
+    }
+
+    let content_type = match fixture.extension().and_then(|extension| extension.to_str()) {
+        Some("xml") => "application/xml",
+        _ => "application/json",
+    };
+    ([(header::CONTENT_TYPE, content_type)], body).into_response()
+}
+
+/// Returns the faults currently injected.
+pub async fn get_faults(State(state): SharedState) -> Response {
+    Json(state.faults()).into_response()
+}
+
+/// Replaces the injected faults.
+pub async fn set_faults(State(state): SharedState, Json(faults): Json<Faults>) -> Response {
+    state.set_faults(faults);
+    Json(state.faults()).into_response()
+}
+
+/// Stops injecting the faults.
+pub async fn clear_faults(State(state): SharedState) -> Response {
+    state.set_faults(Faults::default());
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// Returns all the requests received so far, e.g. to check how many times a request was retried.
+pub async fn requests(State(state): SharedState) -> Response {
+    Json(state.requests()).into_response()
+}
@@ -0,0 +1,97 @@
+use std::{path::PathBuf, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+
+/// The faults injected into the mock responses. A request matching the faults is delayed by
+/// `delay_ms`, then answered with the `status` (if set) or a malformed body (if `malformed`).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Faults {
+    #[serde(default)]
+    pub status: Option<u16>,
+    #[serde(default)]
+    pub malformed: bool,
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// How many of the next matching requests get the faults (`None` means all of them).
+    #[serde(default)]
+    pub count: Option<usize>,
+    /// Only the requests whose path contains this text get the faults.
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+impl Faults {
+    // Check whether the request matches the faults and count it if it does
+    fn take(&mut self, path: &str) -> Option<Faults> {
+        let path_matches = self
+            .path
+            .as_ref()
+            .is_none_or(|fault_path| path.contains(fault_path));
+        if !path_matches || self.count == Some(0) {
+            return None;
+        }
+
+        if let Some(count) = self.count.as_mut() {
+            *count -= 1;
+        }
+        Some(self.clone())
+    }
+}
+
+/// A request received by the mock server and how it was answered.
+#[derive(Debug, Clone, Serialize)]
+pub struct MockRequest {
+    pub path: String,
+    pub status: u16,
+}
+
+/// The state shared by all the mock server requests.
+pub struct MockState {
+    pub fixture_dir: PathBuf,
+    faults: Mutex<Faults>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockState {
+    pub fn new(fixture_dir: PathBuf, faults: Faults) -> Self {
+        Self {
+            fixture_dir,
+            faults: Mutex::new(faults),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn faults(&self) -> Faults {
+        self.faults.lock().expect("Faults lock is poisoned").clone()
+    }
+
+    pub fn set_faults(&self, faults: Faults) {
+        *self.faults.lock().expect("Faults lock is poisoned") = faults;
+    }
+
+    /// Returns the faults to inject into the response to the request with the given path (if any).
+    pub fn take_faults(&self, path: &str) -> Option<Faults> {
+        self.faults
+            .lock()
+            .expect("Faults lock is poisoned")
+            .take(path)
+    }
+
+    pub fn log_request(&self, path: &str, status: u16) {
+        let request = MockRequest {
+            path: path.to_string(),
+            status,
+        };
+        self.requests
+            .lock()
+            .expect("Requests lock is poisoned")
+            .push(request);
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .expect("Requests lock is poisoned")
+            .clone()
+    }
+}
@@ -0,0 +1,53 @@
+mod mock_handlers;
+mod mock_state;
+
+use std::{error::Error, sync::Arc};
+
+use axum::{routing::get, Router};
+
+use crate::commands::MockArgs;
+
+use self::mock_state::{Faults, MockState};
+
+/// Runs a mock Sportradar API serving the responses from the fixture directory (in the layout
+/// written by the `--record` option), so that the real HTTP client with its retries can be
+/// exercised end to end by pointing `SPORT_RADAR_API_BASE_URL` at it.
+///
+/// The faults set by the command options are injected into the responses, and they can be
+/// changed while the server is running:
+///
+/// * `GET /_mock/faults` - the current faults.
+/// * `PUT /_mock/faults` - replaces the faults, e.g. `{"status": 503, "count": 2}` fails the next
+///   two requests. The fields are `status`, `malformed`, `delay_ms`, `count`, and `path`.
+/// * `DELETE /_mock/faults` - stops injecting the faults.
+/// * `GET /_mock/requests` - the received requests (without the API key) and their statuses.
+pub async fn run_mock_server(args: MockArgs) -> Result<(), Box<dyn Error>> {
+    let faults = Faults {
+        status: args.status,
+        malformed: args.malformed,
+        delay_ms: args.delay_ms,
+        count: args.fault_count,
+        path: args.fault_path,
+    };
+    let state = Arc::new(MockState::new(args.fixtures, faults));
+
+    let router = Router::new()
+        .route(
+            "/_mock/faults",
+            get(mock_handlers::get_faults)
+                .put(mock_handlers::set_faults)
+                .delete(mock_handlers::clear_faults),
+        )
+        .route("/_mock/requests", get(mock_handlers::requests))
+        .fallback(mock_handlers::fixture)
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(args.address).await?;
+    println!(
+        "Mock Sportradar API is listening on http://{}",
+        args.address
+    );
+    axum::serve(listener, router).await?;
+
+    Ok(())
+}
@@ -1,15 +1,20 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
+    config::ApiConfig,
     enums::Players,
     models::{Competition, CompetitionSeason, Player, PlayerStatistics, Sport, SportEvent, Team},
-    services::{ApiService, AppStateService},
+    services::{ApiService, AppStateService, FetchProgressService},
 };
 
 #[derive(Debug, Clone)]
 pub struct AppState<HttpClient: ApiService> {
     client: HttpClient,                          // HTTP client with retry middleware
-    sports: HashSet<Sport>,                      // Available sports (in trial mode only soccer)
+    config: ApiConfig,      // API settings (URLs, key, and competition filters)
+    sports: HashSet<Sport>, // Available sports (in trial mode only soccer)
     selected_sport: Option<Sport>, // User selected sport (in trial mode is set by default to soccer)
     competitions: HashSet<Competition>, // Available competitions in selected sport (and only those )
     selected_competition: Option<Competition>, // User selected competition
@@ -20,12 +25,14 @@ pub struct AppState<HttpClient: ApiService> {
     players: HashMap<String, Player>, // All players in selected competition and season
     selected_player_statistics: Option<Players>, // User selected player statistics to show
     limit: Option<usize>,            // Limit to show the info to users
+    progress_observer: Option<Arc<dyn FetchProgressService>>, // Observer of the bulk fetches
 }
 
 impl<HttpClient: ApiService> AppState<HttpClient> {
-    pub fn new(client: HttpClient) -> Self {
+    pub fn new(client: HttpClient, config: ApiConfig) -> Self {
         Self {
             client,
+            config,
             sports: HashSet::new(),
             selected_sport: None,
             competitions: HashSet::new(),
@@ -37,6 +44,7 @@ impl<HttpClient: ApiService> AppState<HttpClient> {
             players: HashMap::new(),
             selected_player_statistics: None,
             limit: None,
+            progress_observer: None,
         }
     }
 }
@@ -57,8 +65,12 @@ impl<HttpClient: ApiService>
         &self.client
     }
 
-    fn sports(&self) -> HashSet<Sport> {
-        self.sports.clone()
+    fn api_config(&self) -> &ApiConfig {
+        &self.config
+    }
+
+    fn sports(&self) -> &HashSet<Sport> {
+        &self.sports
     }
 
     fn set_sports(&mut self, sports: HashSet<Sport>) {
@@ -73,8 +85,8 @@ impl<HttpClient: ApiService>
         self.selected_sport = Some(sport.clone());
     }
 
-    fn competitions(&self) -> HashSet<Competition> {
-        self.competitions.clone()
+    fn competitions(&self) -> &HashSet<Competition> {
+        &self.competitions
     }
 
     fn set_competitions(&mut self, competitions: HashSet<Competition>) {
@@ -89,8 +101,8 @@ impl<HttpClient: ApiService>
         self.selected_competition = Some(competition.clone());
     }
 
-    fn seasons(&self) -> Vec<CompetitionSeason> {
-        self.seasons.clone()
+    fn seasons(&self) -> &[CompetitionSeason] {
+        &self.seasons
     }
 
     fn set_seasons(&mut self, seasons: Vec<CompetitionSeason>) {
@@ -105,24 +117,24 @@ impl<HttpClient: ApiService>
         self.selected_season = Some(season);
     }
 
-    fn sport_events(&self) -> HashSet<SportEvent> {
-        self.sport_events.clone()
+    fn sport_events(&self) -> &HashSet<SportEvent> {
+        &self.sport_events
     }
 
     fn set_sport_events(&mut self, sport_events: HashSet<SportEvent>) {
         self.sport_events = sport_events;
     }
 
-    fn competitors(&self) -> HashSet<Team> {
-        self.competitors.clone()
+    fn competitors(&self) -> &HashSet<Team> {
+        &self.competitors
     }
 
     fn set_competitors(&mut self, competitors: HashSet<Team>) {
         self.competitors = competitors;
     }
 
-    fn players(&self) -> HashMap<String, Player> {
-        self.players.clone()
+    fn players(&self) -> &HashMap<String, Player> {
+        &self.players
     }
 
     fn set_players(&mut self, players: HashMap<String, Player>) {
@@ -137,6 +149,14 @@ impl<HttpClient: ApiService>
         self.limit
     }
 
+    fn progress_observer(&self) -> Option<Arc<dyn FetchProgressService>> {
+        self.progress_observer.clone()
+    }
+
+    fn set_progress_observer(&mut self, observer: Arc<dyn FetchProgressService>) {
+        self.progress_observer = Some(observer);
+    }
+
     fn selected_player_statistics(&self) -> Option<Players> {
         self.selected_player_statistics
     }
@@ -160,4 +180,17 @@ impl<HttpClient: ApiService>
         self.selected_player_statistics = None;
         self.limit = None;
     }
+
+    fn reset_selected_competition(&mut self) {
+        self.selected_competition = None;
+        self.seasons.clear();
+        self.reset_selected_season();
+    }
+
+    fn reset_selected_season(&mut self) {
+        self.selected_season = None;
+        self.sport_events.clear();
+        self.competitors.clear();
+        self.players.clear();
+    }
 }
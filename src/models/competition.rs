@@ -1,14 +1,16 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::{BaseInfoService, CountryService};
 
 use super::country::Country;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct Competition {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
     pub category: Country,
 }
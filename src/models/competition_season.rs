@@ -1,14 +1,16 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::{BaseInfoService, SeasonService};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct CompetitionSeason {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
-    #[serde(default)]
+    #[serde(default, alias = "@disabled")]
     pub disabled: bool,
 }
 
@@ -1,12 +1,14 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::BaseInfoService;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct Country {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
 }
 
@@ -0,0 +1,19 @@
+/// The progress of fetching the statistics of the competitors of a season.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FetchProgress {
+    /// The number of the competitors whose statistics are fetched.
+    pub total: usize,
+    /// The number of the finished requests, both succeeded and failed.
+    pub completed: usize,
+    /// The number of the failed requests.
+    pub failed: usize,
+    /// The number of the retried requests (see the retries of the HTTP client).
+    pub retries: usize,
+}
+
+impl FetchProgress {
+    /// Returns `true` if all the requests are finished.
+    pub fn is_finished(&self) -> bool {
+        self.completed >= self.total
+    }
+}
@@ -2,19 +2,30 @@ mod app_state;
 mod competition;
 mod competition_season;
 mod country;
+mod fetch_progress;
 mod player;
 mod player_statistics;
+mod quota_usage;
+mod request_plan;
+mod response_diagnostics;
+mod season_snapshot;
 mod sport;
 mod sport_event;
+mod sport_event_status;
 mod team;
 
 pub use app_state::AppState;
 pub use competition::Competition;
 pub use competition_season::CompetitionSeason;
-#[allow(unused)]
 pub use country::Country;
+pub use fetch_progress::FetchProgress;
 pub use player::Player;
 pub use player_statistics::PlayerStatistics;
+pub use quota_usage::{DailyUsage, QuotaUsage};
+pub use request_plan::{PlannedRequest, RequestPlan};
+pub use response_diagnostics::{DiagnosticsReport, ResponseDiagnostics, SkippedItem};
+pub use season_snapshot::{SeasonData, SeasonSnapshot};
 pub use sport::Sport;
 pub use sport_event::SportEvent;
+pub use sport_event_status::SportEventStatus;
 pub use team::Team;
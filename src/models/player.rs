@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::{
     BaseInfoService, PlayerSeasonStatisticsService, PlayerService, PlayerStatisticsService,
@@ -8,14 +8,18 @@ use crate::services::{
 
 use super::{PlayerStatistics, Team};
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct Player {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
+    // The API leaves out the statistics of the players who did not play, they count as zero
+    #[serde(default)]
     pub statistics: PlayerStatistics,
     #[serde(default)]
     pub season_statistics: PlayerStatistics,
-    #[serde(skip)]
+    #[serde(default)]
     team: Team,
 }
 
@@ -39,6 +43,10 @@ impl PlayerService<PlayerStatistics, Team> for Player {
     fn set_team(&mut self, team: &Team) {
         self.team = team.clone();
     }
+
+    fn team(&self) -> &Team {
+        &self.team
+    }
 }
 
 impl PlayerStatisticsService for Player {
@@ -1,10 +1,14 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::PlayerStatisticsService;
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash, Default)]
+// The API leaves out the zero statistics
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Default)]
+#[serde(default)]
 pub struct PlayerStatistics {
+    #[serde(alias = "@assists")]
     pub assists: u8,
+    #[serde(alias = "@goals_scored")]
     pub goals_scored: u8,
 }
 
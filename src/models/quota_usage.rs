@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The number of the API requests sent on one day, by the endpoint name (see
+/// [`crate::enums::Endpoint::names`], the unknown endpoints are counted as `other`).
+pub type DailyUsage = BTreeMap<String, u64>;
+
+/// The number of the API requests sent with every API key, kept in the local state file (see
+/// [`crate::helpers::quota_usage`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    /// The usage by the id of the API key (see [`crate::helpers::api_key_id`]) and the day
+    /// (`YYYY-MM-DD`, UTC).
+    pub keys: BTreeMap<String, BTreeMap<String, DailyUsage>>,
+    /// The masked API keys (see [`crate::helpers::mask_api_key`]) by the id of the key, to show
+    /// which key the usage is of.
+    #[serde(default)]
+    pub masked_keys: BTreeMap<String, String>,
+}
+
+impl QuotaUsage {
+    /// Creates an empty usage.
+    pub const fn new() -> Self {
+        Self {
+            keys: BTreeMap::new(),
+            masked_keys: BTreeMap::new(),
+        }
+    }
+
+    /// Counts a request sent to the endpoint with the key on the day.
+    pub fn record(&mut self, key: &str, day: &str, endpoint: &str) {
+        *self
+            .keys
+            .entry(key.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+            .entry(endpoint.to_string())
+            .or_default() += 1;
+    }
+
+    /// Returns `true` if no requests are counted.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Adds the requests counted in the other usage to this one.
+    pub fn merge(&mut self, other: &QuotaUsage) {
+        for (key, days) in &other.keys {
+            for (day, usage) in days {
+                for (endpoint, count) in usage {
+                    *self
+                        .keys
+                        .entry(key.clone())
+                        .or_default()
+                        .entry(day.clone())
+                        .or_default()
+                        .entry(endpoint.clone())
+                        .or_default() += count;
+                }
+            }
+        }
+        self.masked_keys.extend(
+            other
+                .masked_keys
+                .iter()
+                .map(|(key, masked)| (key.clone(), masked.clone())),
+        );
+    }
+
+    /// Returns the masked form of the key to show, or the key itself if it is not known.
+    pub fn display_key<'a>(&'a self, key: &'a str) -> &'a str {
+        self.masked_keys.get(key).map_or(key, String::as_str)
+    }
+
+    /// Returns the usage of the key on the days of the month (`YYYY-MM`), by the day.
+    pub fn month_days<'a>(
+        &'a self,
+        key: &str,
+        month: &'a str,
+    ) -> impl Iterator<Item = (&'a String, &'a DailyUsage)> {
+        self.keys
+            .get(key)
+            .into_iter()
+            .flatten()
+            .filter(move |(day, _)| day.starts_with(month))
+    }
+
+    /// Returns the number of the requests sent with the key in the month (`YYYY-MM`), by the
+    /// endpoint name.
+    pub fn month_endpoints(&self, key: &str, month: &str) -> DailyUsage {
+        let mut endpoints = DailyUsage::new();
+        for (endpoint, count) in self
+            .month_days(key, month)
+            .flat_map(|(_, usage)| usage.iter())
+        {
+            *endpoints.entry(endpoint.clone()).or_default() += count;
+        }
+
+        endpoints
+    }
+
+    /// Returns the number of the requests sent with the key in the month (`YYYY-MM`).
+    pub fn month_total(&self, key: &str, month: &str) -> u64 {
+        self.month_days(key, month)
+            .flat_map(|(_, usage)| usage.values())
+            .sum()
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// An API request a fetch would send.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedRequest {
+    /// The name of the endpoint (see [`crate::enums::Endpoint::names`]).
+    pub endpoint: &'static str,
+    /// The URL of the request without the API key.
+    pub url: String,
+}
+
+/// The API requests a fetch would send, built without sending any of them (see
+/// [`crate::services::AppStateService::season_request_plan`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RequestPlan {
+    /// The planned requests, in the order they would be sent.
+    pub requests: Vec<PlannedRequest>,
+    /// `false` if the requests depending on the data which is not fetched yet (e.g. one request
+    /// per competitor of the season before the schedules are fetched) are missing in the plan.
+    pub is_complete: bool,
+}
+
+impl RequestPlan {
+    /// Returns how long sending the planned requests takes at the rate limit (requests per second)
+    /// of the API key, the retries not included.
+    pub fn estimated_duration(&self, rate_limit: u32) -> Duration {
+        Duration::from_secs_f64(self.requests.len() as f64 / f64::from(rate_limit.max(1)))
+    }
+}
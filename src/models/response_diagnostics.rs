@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+/// A list item skipped because it could not be deserialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedItem {
+    /// The JSON path of the item, e.g. `competitor.players[3]`.
+    pub path: String,
+    /// Why the item could not be deserialized.
+    pub error: String,
+}
+
+/// The differences between an API response and the models found while deserializing the
+/// response. The field paths have the list indices removed (e.g. `competitor.players[].age`),
+/// so every field is listed once.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResponseDiagnostics {
+    /// The list items skipped because they could not be deserialized.
+    pub skipped_items: Vec<SkippedItem>,
+    /// The required fields missing in the skipped items.
+    pub missing_fields: BTreeSet<String>,
+    /// The fields of the response which are not in the models.
+    pub unexpected_fields: BTreeSet<String>,
+}
+
+impl ResponseDiagnostics {
+    /// Returns `true` if the response matches the models exactly.
+    pub fn is_empty(&self) -> bool {
+        self.skipped_items.is_empty()
+            && self.missing_fields.is_empty()
+            && self.unexpected_fields.is_empty()
+    }
+}
+
+/// The diagnostics of the API responses, by the response URL (without the API key).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DiagnosticsReport {
+    pub responses: BTreeMap<String, ResponseDiagnostics>,
+}
+
+impl DiagnosticsReport {
+    /// Returns the number of the list items skipped in all the responses.
+    pub fn skipped_items_count(&self) -> usize {
+        self.responses
+            .values()
+            .map(|diagnostics| diagnostics.skipped_items.len())
+            .sum()
+    }
+}
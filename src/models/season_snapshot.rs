@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Competition, CompetitionSeason, Player, SportEvent, Team};
+
+/// A snapshot of all the data loaded for a single season. Snapshots are saved to the local
+/// cache after a season is loaded, so that the season data can be used later without fetching
+/// it from the API again.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SeasonSnapshot<Competition, Season, SportEvent, Competitor, Player> {
+    pub competition: Competition,
+    pub season: Season,
+    pub sport_events: Vec<SportEvent>,
+    pub competitors: Vec<Competitor>,
+    pub players: Vec<Player>,
+    /// When the season was fetched from the API, in the RFC 3339 format (UTC). Missing in the
+    /// snapshots saved before it was recorded.
+    #[serde(default)]
+    pub fetched_at: Option<String>,
+}
+
+/// The season snapshot of the models used by the application.
+pub type SeasonData = SeasonSnapshot<Competition, CompetitionSeason, SportEvent, Team, Player>;
@@ -1,32 +1,41 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::services::{BaseInfoService, ConstructService};
+use crate::{
+    config::SportApi,
+    services::{BaseInfoService, SportApiService},
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct Sport {
-    pub id: String,
-    pub name: String,
+    #[serde(flatten)]
+    pub api: SportApi,
 }
 
 impl Display for Sport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", self.api.name)
     }
 }
 
 impl BaseInfoService for Sport {
     fn id(&self) -> String {
-        self.id.clone()
+        self.api.id.clone()
     }
     fn name(&self) -> String {
-        self.name.clone().to_lowercase()
+        self.api.name.clone()
     }
 }
 
-impl ConstructService for Sport {
-    fn construct(id: String, name: String) -> Self {
-        Self { id, name }
+impl SportApiService for Sport {
+    fn from_sport_api(sport_api: &SportApi) -> Self {
+        Self {
+            api: sport_api.clone(),
+        }
+    }
+
+    fn sport_api(&self) -> &SportApi {
+        &self.api
     }
 }
@@ -1,13 +1,25 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::services::CompetitorsService;
+use crate::{
+    helpers::deserialize_list,
+    services::{CompetitorsService, SportEventStatusService},
+};
 
-use super::Team;
+use super::{SportEventStatus, Team};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct SportEvent {
+    #[serde(alias = "@id")]
     pub id: String,
+    /// The scheduled kickoff time in the RFC 3339 format, e.g. `2023-08-11T19:00:00+00:00`.
+    #[serde(default, alias = "@start_time")]
+    pub start_time: Option<String>,
+    #[serde(deserialize_with = "deserialize_list")]
     competitors: Vec<Team>,
+    /// The status and the result, set from the schedule listing the sport event. Missing in the
+    /// snapshots saved before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sport_event_status: Option<SportEventStatus>,
 }
 
 impl CompetitorsService<Team> for SportEvent {
@@ -15,3 +27,13 @@ impl CompetitorsService<Team> for SportEvent {
         self.competitors.clone()
     }
 }
+
+impl SportEventStatusService for SportEvent {
+    fn status(&self) -> Option<SportEventStatus> {
+        self.sport_event_status.clone()
+    }
+
+    fn set_status(&mut self, status: SportEventStatus) {
+        self.sport_event_status = Some(status);
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// The status and the result of a sport event, from the `sport_event_status` of the schedules.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub struct SportEventStatus {
+    /// The status of the sport event, e.g. `not_started`, `live`, or `closed`.
+    #[serde(default, alias = "@status")]
+    pub status: String,
+    #[serde(default, alias = "@home_score")]
+    pub home_score: Option<u8>,
+    #[serde(default, alias = "@away_score")]
+    pub away_score: Option<u8>,
+}
+
+impl SportEventStatus {
+    /// Returns the final score (home, away) of the sport event, or `None` until the sport event
+    /// is closed (the result is confirmed) or if the API omits the score.
+    pub fn final_score(&self) -> Option<(u8, u8)> {
+        match (self.status.as_str(), self.home_score, self.away_score) {
+            ("closed" | "ended", Some(home), Some(away)) => Some((home, away)),
+            _ => None,
+        }
+    }
+}
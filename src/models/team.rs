@@ -1,12 +1,14 @@
 use std::fmt::Display;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::services::{BaseInfoService, ConstructService};
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Default)]
 pub struct Team {
+    #[serde(alias = "@id")]
     pub id: String,
+    #[serde(alias = "@name")]
     pub name: String,
 }
 
@@ -0,0 +1,78 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sportradar::helpers::file_safe_id;
+
+use super::{
+    player_profile::PlayerProfile,
+    season_report::{PlayerEntry, SeasonReport},
+    svg_chart::{horizontal_bar_chart, radar_chart, Bar, RadarSeries},
+};
+
+/// Writes the SVG charts of the season into the directory: a horizontal bar chart per leaderboard
+/// (`<season>_<metric>.svg`), and a radar chart of the percentile profile (see [`PlayerProfile`])
+/// against the league average per player (`<season>_<player>.svg`).
+///
+/// # Returns
+/// The paths of the written charts.
+pub fn write_charts(
+    dir: &Path,
+    report: &SeasonReport,
+    players: &[&PlayerEntry],
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let file =
+        |id: &str, name: &str| dir.join(format!("{}_{}.svg", file_safe_id(id), file_safe_id(name)));
+    let mut paths = Vec::new();
+
+    for leaderboard in &report.leaderboards {
+        let bars: Vec<Bar> = leaderboard
+            .entries
+            .iter()
+            .map(|entry| Bar::new(format!("{} ({})", entry.player, entry.team), entry.value))
+            .collect();
+        let title = format!(
+            "{} {}: {}",
+            report.competition.name, report.season.name, leaderboard.title
+        );
+        let path = file(&report.season.id, &leaderboard.metric);
+        fs::write(&path, horizontal_bar_chart(&title, &bars))?;
+        paths.push(path);
+    }
+
+    for player in players {
+        let profile = PlayerProfile::new(report, player);
+        let axes: Vec<String> = profile
+            .metrics
+            .iter()
+            .map(|metric| metric.label())
+            .collect();
+        let axes: Vec<&str> = axes.iter().map(String::as_str).collect();
+        let series = [
+            RadarSeries {
+                label: "League average".to_string(),
+                values: profile.league_average,
+                color: "#888888",
+                dashed: true,
+            },
+            RadarSeries {
+                label: player.name.clone(),
+                values: profile.player,
+                color: "#2f6fa7",
+                dashed: false,
+            },
+        ];
+        let title = format!(
+            "{} ({}) - percentiles in {}",
+            player.name, player.team, report.season.name
+        );
+        let path = file(&report.season.id, &player.id);
+        fs::write(&path, radar_chart(&title, &axes, &series))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
@@ -0,0 +1,341 @@
+use std::{
+    error::Error,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use sportradar::{enums::Players, helpers::file_safe_id};
+
+use super::{
+    capitalize, escape,
+    season_report::{Fixture, PlayerEntry, SeasonReport},
+    svg_chart::{horizontal_bar_chart, Bar},
+};
+
+// The inline stylesheet of every page, the report has no external assets
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem auto; max-width: 60rem; padding: 0 1rem; color: #222; }
+h1, h2 { color: #1f4e78; }
+nav { margin-bottom: 1rem; }
+.meta { color: #666; font-size: 0.9rem; }
+table { border-collapse: collapse; margin: 1rem 0 2rem; min-width: 50%; }
+th, td { border-bottom: 1px solid #ddd; padding: 0.3rem 0.6rem; text-align: left; }
+th { background: #1f4e78; color: #fff; cursor: pointer; user-select: none; }
+th[data-order=asc]::after { content: ' \\25B2'; }
+th[data-order=desc]::after { content: ' \\25BC'; }
+tr:nth-child(even) td { background: #f4f7fb; }
+td.number { text-align: right; }
+a { color: #2f6fa7; }
+";
+
+// Sort the rows of a `sortable` table by the clicked column, as numbers if all the cells are numbers
+const SORT_SCRIPT: &str = "
+document.querySelectorAll('table.sortable th').forEach(th => th.addEventListener('click', () => {
+  const body = th.closest('table').tBodies[0];
+  const ascending = th.dataset.order !== 'asc';
+  th.closest('tr').querySelectorAll('th').forEach(other => delete other.dataset.order);
+  th.dataset.order = ascending ? 'asc' : 'desc';
+  const key = row => {
+    const text = row.cells[th.cellIndex].textContent.trim();
+    return text !== '' && !isNaN(text) ? Number(text) : text.toLowerCase();
+  };
+  [...body.rows]
+    .sort((a, b) => {
+      const [x, y] = [key(a), key(b)];
+      const order = typeof x === 'number' && typeof y === 'number' ? x - y : String(x).localeCompare(String(y));
+      return ascending ? order : -order;
+    })
+    .forEach(row => body.appendChild(row));
+}));
+";
+
+// Get the file name of the page of a team or a player
+fn page_file(id: &str) -> String {
+    format!("{}.html", file_safe_id(id))
+}
+
+// Render a complete page, `root` is the relative path of the report's directory
+fn page(report: &SeasonReport, title: &str, root: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<nav><a href="{root}index.html">{competition} - {season}</a></nav>
+{body}
+<p class="meta">Fetched at {fetched_at}, generated at {generated_at}.</p>
+<script>{SORT_SCRIPT}</script>
+</body>
+</html>
+"#,
+        title = escape(title),
+        competition = escape(&report.competition.name),
+        season = escape(&report.season.name),
+        fetched_at = report.fetched_at.as_deref().unwrap_or("an unknown time"),
+        generated_at = report.generated_at,
+    )
+}
+
+// Render a sortable table, the cells are HTML already
+fn table(headers: &[&str], rows: impl IntoIterator<Item = Vec<String>>) -> String {
+    let mut html = String::from(r#"<table class="sortable"><thead><tr>"#);
+    for header in headers {
+        let _ = write!(html, "<th>{}</th>", escape(header));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            // The numbers are aligned to the right
+            let class = if !cell.is_empty() && cell.parse::<f64>().is_ok() {
+                r#" class="number""#
+            } else {
+                ""
+            };
+            let _ = write!(html, "<td{}>{}</td>", class, cell);
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+// Render a link to the page of a team or a player, or only the name if the id is unknown
+fn link(root: &str, kind: &str, id: &str, name: &str) -> String {
+    if id.is_empty() {
+        return escape(name);
+    }
+    format!(
+        r#"<a href="{}{}/{}">{}</a>"#,
+        root,
+        kind,
+        page_file(id),
+        escape(name)
+    )
+}
+
+// Format the kickoff time as e.g. `2024-08-17 14:00 UTC`
+fn kickoff_text(fixture: &Fixture) -> String {
+    match &fixture.kickoff {
+        Some(kickoff) if kickoff.len() >= 16 => {
+            format!("{} {} UTC", &kickoff[..10], &kickoff[11..16])
+        }
+        _ => String::new(),
+    }
+}
+
+fn fixtures_table<'a>(root: &str, fixtures: impl IntoIterator<Item = &'a Fixture>) -> String {
+    table(
+        &["Kickoff", "Home", "Away"],
+        fixtures.into_iter().map(|fixture| {
+            vec![
+                kickoff_text(fixture),
+                link(root, "teams", &fixture.home_id, &fixture.home),
+                link(root, "teams", &fixture.away_id, &fixture.away),
+            ]
+        }),
+    )
+}
+
+fn index_page(report: &SeasonReport) -> String {
+    let title = format!("{} - {}", report.competition.name, report.season.name);
+    let mut body = format!(
+        r#"<h1>{}</h1><p class="meta">{}</p>"#,
+        escape(&title),
+        escape(&report.competition.country)
+    );
+
+    body.push_str("<h2>League Table</h2>");
+    body.push_str(&table(
+        &[
+            "Rank", "Team", "Played", "Won", "Drawn", "Lost", "GF", "GA", "GD", "Points",
+        ],
+        report.table.iter().map(|entry| {
+            vec![
+                entry.rank.to_string(),
+                link("", "teams", &entry.team_id, &entry.team),
+                entry.played.to_string(),
+                entry.won.to_string(),
+                entry.drawn.to_string(),
+                entry.lost.to_string(),
+                entry.goals_for.to_string(),
+                entry.goals_against.to_string(),
+                entry.goal_difference.to_string(),
+                entry.points.to_string(),
+            ]
+        }),
+    ));
+
+    for leaderboard in &report.leaderboards {
+        let _ = write!(body, "<h2>{}</h2>", escape(&leaderboard.title));
+        let bars: Vec<Bar> = leaderboard
+            .entries
+            .iter()
+            .map(|entry| Bar::new(entry.player.as_str(), entry.value))
+            .collect();
+        body.push_str(&horizontal_bar_chart(&leaderboard.title, &bars));
+        body.push_str(&table(
+            &["Rank", "Player", "Team", &capitalize(&leaderboard.metric)],
+            leaderboard.entries.iter().map(|entry| {
+                vec![
+                    entry.rank.to_string(),
+                    link("", "players", &entry.player_id, &entry.player),
+                    link("", "teams", &entry.team_id, &entry.team),
+                    entry.value.to_string(),
+                ]
+            }),
+        ));
+    }
+
+    body.push_str("<h2>Fixtures</h2>");
+    body.push_str(&fixtures_table("", &report.fixtures));
+
+    page(report, &title, "", &body)
+}
+
+fn team_page(report: &SeasonReport, team_id: &str) -> String {
+    let entry = report.table.iter().find(|entry| entry.team_id == team_id);
+    let players: Vec<&PlayerEntry> = report
+        .players
+        .iter()
+        .filter(|player| player.team_id == team_id)
+        .collect();
+    let name = entry.map_or("", |entry| entry.team.as_str());
+
+    let mut body = format!("<h1>{}</h1>", escape(name));
+    if let Some(entry) = entry {
+        let _ = write!(
+            body,
+            "<p>Rank {} of {} with {} points: {} won, {} drawn, {} lost, {} goals scored, {} conceded.</p>",
+            entry.rank,
+            report.table.len(),
+            entry.points,
+            entry.won,
+            entry.drawn,
+            entry.lost,
+            entry.goals_for,
+            entry.goals_against
+        );
+    }
+
+    let bars: Vec<Bar> = players
+        .iter()
+        .filter(|player| player.goals > 0)
+        .sorted_by(|a, b| b.goals.cmp(&a.goals).then_with(|| a.name.cmp(&b.name)))
+        .map(|player| Bar::new(player.name.as_str(), player.goals))
+        .collect();
+    if !bars.is_empty() {
+        body.push_str(&horizontal_bar_chart("Goals", &bars));
+    }
+
+    body.push_str("<h2>Players</h2>");
+    body.push_str(&table(
+        &["Player", "Goals", "Assists"],
+        players.iter().map(|player| {
+            vec![
+                link("../", "players", &player.id, &player.name),
+                player.goals.to_string(),
+                player.assists.to_string(),
+            ]
+        }),
+    ));
+
+    body.push_str("<h2>Fixtures</h2>");
+    body.push_str(&fixtures_table(
+        "../",
+        report
+            .fixtures
+            .iter()
+            .filter(|fixture| fixture.home_id == team_id || fixture.away_id == team_id),
+    ));
+
+    page(report, name, "../", &body)
+}
+
+fn player_page(report: &SeasonReport, player: &PlayerEntry) -> String {
+    let mut body = format!(
+        "<h1>{}</h1><p>{}</p>",
+        escape(&player.name),
+        link("../", "teams", &player.team_id, &player.team)
+    );
+
+    let mut rows = Vec::new();
+    let mut charts = String::new();
+    for metric in Players::METRICS {
+        let values: Vec<u8> = report
+            .players
+            .iter()
+            .map(|player| player.value(metric))
+            .collect();
+        let average =
+            values.iter().map(|value| f64::from(*value)).sum::<f64>() / values.len().max(1) as f64;
+        let best = values.iter().copied().max().unwrap_or_default();
+        let value = player.value(metric);
+
+        rows.push(vec![
+            metric.column_title().to_string(),
+            value.to_string(),
+            report.rank_of(metric, player).to_string(),
+            format!("{:.1}", average),
+            best.to_string(),
+        ]);
+        charts.push_str(&horizontal_bar_chart(
+            metric.column_title(),
+            &[
+                Bar::new(player.name.as_str(), value),
+                Bar::new("Season average", average),
+                Bar::new("Season best", best),
+            ],
+        ));
+    }
+
+    body.push_str(&table(
+        &["Metric", "Value", "Rank", "Season Average", "Season Best"],
+        rows,
+    ));
+    body.push_str(&charts);
+
+    page(report, &player.name, "../", &body)
+}
+
+/// Writes the report as a static site into the directory: the `index.html` page with the league
+/// table, the leaderboards, and the fixtures, and a page per team (`teams/`) and per player
+/// (`players/`). The pages have no external assets, the styles, the scripts sorting the tables,
+/// and the SVG charts are inline.
+///
+/// # Returns
+/// The path of the `index.html` page.
+pub fn write_html_report(dir: &Path, report: &SeasonReport) -> Result<PathBuf, Box<dyn Error>> {
+    fs::create_dir_all(dir.join("teams"))?;
+    fs::create_dir_all(dir.join("players"))?;
+
+    let team_ids = report
+        .table
+        .iter()
+        .map(|entry| entry.team_id.as_str())
+        .filter(|team_id| !team_id.is_empty());
+    for team_id in team_ids {
+        fs::write(
+            dir.join("teams").join(page_file(team_id)),
+            team_page(report, team_id),
+        )?;
+    }
+    for player in &report.players {
+        fs::write(
+            dir.join("players").join(page_file(&player.id)),
+            player_page(report, player),
+        )?;
+    }
+
+    let index = dir.join("index.html");
+    fs::write(&index, index_page(report))?;
+
+    Ok(index)
+}
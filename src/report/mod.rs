@@ -0,0 +1,98 @@
+mod chart_export;
+mod html_report;
+mod player_profile;
+mod season_report;
+mod svg_chart;
+mod template_report;
+
+use std::{collections::HashSet, error::Error, fs};
+
+use itertools::Itertools;
+use sportradar::helpers::{get_cache_dir, load_cached_season};
+
+use crate::{
+    commands::{ChartsArgs, ReportArgs},
+    export::ExportedSeason,
+};
+
+use self::season_report::SeasonReport;
+
+// Escape the text for HTML and SVG
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+// Capitalize the metric name for the headers and the labels, e.g. `Goals`
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Renders the report of a season from the local cache as a static HTML site, or with a template,
+/// without calling the API.
+pub fn run_report(args: &ReportArgs) -> Result<(), Box<dyn Error>> {
+    let snapshot = load_cached_season(&get_cache_dir(), args.season.as_deref())?;
+    let season = ExportedSeason::new(snapshot, args.limit);
+    let report = SeasonReport::new(&season);
+
+    let Some(template) = &args.template else {
+        let dir = args.output.clone().unwrap_or_else(|| "report".into());
+        let index = html_report::write_html_report(&dir, &report)?;
+        println!("Report written to {}", index.display());
+        return Ok(());
+    };
+
+    let output = template_report::render_template(template, &report)?;
+    match &args.output {
+        Some(path) => fs::write(path, output)?,
+        None => print!("{}", output),
+    }
+
+    Ok(())
+}
+
+/// Writes the SVG charts of a season from the local cache (the leaderboards and the player
+/// profiles), without calling the API.
+pub fn run_charts(args: &ChartsArgs) -> Result<(), Box<dyn Error>> {
+    let snapshot = load_cached_season(&get_cache_dir(), args.season.as_deref())?;
+    let season = ExportedSeason::new(snapshot, args.limit);
+    let report = SeasonReport::new(&season);
+
+    // The players on the leaderboards by default, each of them once
+    let player_ids: Vec<&str> = if args.players.is_empty() {
+        report
+            .leaderboards
+            .iter()
+            .flat_map(|leaderboard| &leaderboard.entries)
+            .map(|entry| entry.player_id.as_str())
+            .unique()
+            .collect()
+    } else {
+        args.players.iter().map(String::as_str).collect()
+    };
+    let wanted: HashSet<&str> = player_ids.iter().copied().collect();
+    let players: Vec<_> = report
+        .players
+        .iter()
+        .filter(|player| wanted.contains(player.id.as_str()))
+        .collect();
+    if let Some(missing) = player_ids
+        .iter()
+        .find(|id| !players.iter().any(|player| player.id == **id))
+    {
+        return Err(format!("Player {} is not in the season", missing).into());
+    }
+
+    for path in chart_export::write_charts(&args.output, &report, &players)? {
+        println!("Chart written to {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use sportradar::enums::Players;
+
+use super::season_report::{PlayerEntry, SeasonReport};
+
+/// A statistics of the player profiles: a leaderboard metric (see [`Players::METRICS`]) or a value
+/// derived from them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileMetric {
+    Metric(Players),
+    /// The goals and the assists together.
+    GoalContributions,
+    /// The share of the goals of the player's team scored by the player.
+    TeamGoalShare,
+}
+
+impl ProfileMetric {
+    /// Returns the statistics of the profiles: the leaderboard metrics and the derived values.
+    pub fn all() -> Vec<ProfileMetric> {
+        Players::METRICS
+            .into_iter()
+            .map(ProfileMetric::Metric)
+            .chain([
+                ProfileMetric::GoalContributions,
+                ProfileMetric::TeamGoalShare,
+            ])
+            .collect()
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ProfileMetric::Metric(metric) => metric.column_title().to_string(),
+            ProfileMetric::GoalContributions => "Goals + Assists".to_string(),
+            ProfileMetric::TeamGoalShare => "Share of Team Goals".to_string(),
+        }
+    }
+
+    // Get the player's value of the statistics, the team goals are needed for the share
+    fn value(&self, player: &PlayerEntry, team_goals: &HashMap<&str, u32>) -> f64 {
+        match self {
+            ProfileMetric::Metric(metric) => f64::from(player.value(*metric)),
+            ProfileMetric::GoalContributions => f64::from(player.goals) + f64::from(player.assists),
+            ProfileMetric::TeamGoalShare => match team_goals.get(player.team_id.as_str()) {
+                Some(goals) if *goals > 0 => f64::from(player.goals) / f64::from(*goals),
+                _ => 0.0,
+            },
+        }
+    }
+}
+
+/// The percentiles of a player and of the league average among the players of the season, per
+/// statistics of [`ProfileMetric::all`].
+#[derive(Debug, Clone)]
+pub struct PlayerProfile {
+    pub metrics: Vec<ProfileMetric>,
+    pub player: Vec<f64>,
+    pub league_average: Vec<f64>,
+}
+
+// Get the percentile rank of the value among the values (0 to 100), the equal values count half
+fn percentile(value: f64, values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let below = values.iter().filter(|other| **other < value).count() as f64;
+    let equal = values.iter().filter(|other| **other == value).count() as f64;
+
+    100.0 * (below + equal / 2.0) / values.len() as f64
+}
+
+impl PlayerProfile {
+    /// Builds the percentile profile of the player among all the players of the report's season,
+    /// and the profile of the league average (the percentiles of the average values).
+    pub fn new(report: &SeasonReport, player: &PlayerEntry) -> Self {
+        let team_goals: HashMap<&str, u32> = report
+            .table
+            .iter()
+            .map(|entry| (entry.team_id.as_str(), entry.player_goals))
+            .collect();
+        let metrics = ProfileMetric::all();
+
+        let mut player_percentiles = Vec::new();
+        let mut average_percentiles = Vec::new();
+        for metric in &metrics {
+            let values: Vec<f64> = report
+                .players
+                .iter()
+                .map(|other| metric.value(other, &team_goals))
+                .collect();
+            let average = values.iter().sum::<f64>() / values.len().max(1) as f64;
+            player_percentiles.push(percentile(metric.value(player, &team_goals), &values));
+            average_percentiles.push(percentile(average, &values));
+        }
+
+        Self {
+            metrics,
+            player: player_percentiles,
+            league_average: average_percentiles,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use itertools::Itertools;
+use serde::Serialize;
+use sportradar::{
+    enums::Players,
+    helpers::{format_utc, now_utc},
+    models::Player,
+    services::{BaseInfoService, CompetitorsService, PlayerSeasonStatisticsService, PlayerService},
+};
+
+use crate::export::{kickoff_millis, league_table, ExportedSeason};
+
+/// The data of a season report: the competition and the season, the league table, a leaderboard
+/// per metric (see [`Players::METRICS`]), all the players, and the fixtures.
+#[derive(Debug, Serialize)]
+pub struct SeasonReport {
+    pub competition: ReportCompetition,
+    pub season: ReportSeason,
+    /// When the season was fetched from the API (RFC 3339), if known.
+    pub fetched_at: Option<String>,
+    /// When the report was generated (RFC 3339).
+    pub generated_at: String,
+    /// The teams ranked by the results of the closed sport events, see [`league_table`].
+    pub table: Vec<TableEntry>,
+    pub leaderboards: Vec<Leaderboard>,
+    /// The players sorted by the team and the name.
+    pub players: Vec<PlayerEntry>,
+    /// The sport events sorted by the kickoff time, the ones without it last.
+    pub fixtures: Vec<Fixture>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportCompetition {
+    pub id: String,
+    pub name: String,
+    pub country: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportSeason {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TableEntry {
+    pub rank: usize,
+    pub team_id: String,
+    pub team: String,
+    /// The number of the sport events of the season the team plays in.
+    pub matches: u32,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub goals_for: u32,
+    pub goals_against: u32,
+    pub goal_difference: i64,
+    pub points: u32,
+    /// The season goals of the team's players.
+    pub player_goals: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Leaderboard {
+    /// The name of the metric, e.g. `goals`.
+    pub metric: String,
+    /// The title of the leaderboard, e.g. `Top Goal Scorers`.
+    pub title: String,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub player_id: String,
+    pub player: String,
+    pub team_id: String,
+    pub team: String,
+    pub value: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlayerEntry {
+    pub id: String,
+    pub name: String,
+    pub team_id: String,
+    pub team: String,
+    pub goals: u8,
+    pub assists: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Fixture {
+    pub id: String,
+    /// The kickoff time (RFC 3339, UTC), if known.
+    pub kickoff: Option<String>,
+    pub home_id: String,
+    pub home: String,
+    pub away_id: String,
+    pub away: String,
+}
+
+impl SeasonReport {
+    /// Builds the report of the season. The leaderboards have as many players as the limit of the
+    /// season, ranked the same way as in the interactive mode.
+    pub fn new(season: &ExportedSeason) -> Self {
+        let data = &season.data;
+        let (competition, selected_season) = (&data.competition, &data.season);
+
+        let table = league_table(data)
+            .into_iter()
+            .enumerate()
+            .map(|(index, team)| TableEntry {
+                rank: index + 1,
+                played: team.played(),
+                goal_difference: team.goal_difference(),
+                points: team.points(),
+                team_id: team.id,
+                team: team.name,
+                matches: team.matches,
+                won: team.won,
+                drawn: team.drawn,
+                lost: team.lost,
+                goals_for: team.goals_for,
+                goals_against: team.goals_against,
+                player_goals: team.goals,
+            })
+            .collect();
+
+        let leaderboards = Players::METRICS
+            .iter()
+            .map(|metric| Leaderboard {
+                metric: metric.metric_name().to_string(),
+                title: metric.to_string(),
+                entries: season
+                    .top_players(*metric)
+                    .iter()
+                    .enumerate()
+                    .map(|(index, player)| LeaderboardEntry {
+                        rank: index + 1,
+                        player_id: player.id(),
+                        player: player.name(),
+                        team_id: player.team().id(),
+                        team: player.team().name(),
+                        value: metric.season_value(player),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let players = data
+            .players
+            .iter()
+            .sorted_by_key(|player| (player.team().name(), player.name()))
+            .map(PlayerEntry::from)
+            .collect();
+
+        let fixtures = data
+            .sport_events
+            .iter()
+            .map(|sport_event| (kickoff_millis(sport_event), sport_event))
+            .sorted_by_key(|(kickoff, sport_event)| {
+                (kickoff.is_none(), *kickoff, sport_event.id.clone())
+            })
+            .map(|(kickoff, sport_event)| {
+                // The API lists the home competitor first
+                let competitors = sport_event.competitors();
+                let team = |index: usize| competitors.get(index).cloned().unwrap_or_default();
+                let (home, away) = (team(0), team(1));
+                Fixture {
+                    id: sport_event.id.clone(),
+                    kickoff: kickoff
+                        .and_then(|millis| u64::try_from(millis / 1000).ok())
+                        .map(format_utc),
+                    home_id: home.id,
+                    home: home.name,
+                    away_id: away.id,
+                    away: away.name,
+                }
+            })
+            .collect();
+
+        Self {
+            competition: ReportCompetition {
+                id: competition.id(),
+                name: competition.name(),
+                country: competition.category.name(),
+            },
+            season: ReportSeason {
+                id: selected_season.id(),
+                name: selected_season.name(),
+            },
+            fetched_at: data.fetched_at.clone(),
+            generated_at: now_utc(),
+            table,
+            leaderboards,
+            players,
+            fixtures,
+        }
+    }
+
+    /// Returns the rank of the player by the metric among all the players of the season.
+    pub fn rank_of(&self, metric: Players, player: &PlayerEntry) -> usize {
+        let value = player.value(metric);
+
+        // The players with the same value share the rank
+        1 + self
+            .players
+            .iter()
+            .filter(|other| other.value(metric) > value)
+            .count()
+    }
+}
+
+impl PlayerEntry {
+    /// Returns the player's value of the metric.
+    pub fn value(&self, metric: Players) -> u8 {
+        match metric {
+            Players::TopGoalScorers => self.goals,
+            Players::TopAssistants => self.assists,
+        }
+    }
+}
+
+impl From<&Player> for PlayerEntry {
+    fn from(player: &Player) -> Self {
+        Self {
+            id: player.id(),
+            name: player.name(),
+            team_id: player.team().id(),
+            team: player.team().name(),
+            goals: player.season_goals(),
+            assists: player.season_assists(),
+        }
+    }
+}
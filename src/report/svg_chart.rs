@@ -0,0 +1,183 @@
+use std::fmt::Write;
+
+use super::escape;
+
+// The layout of the bar charts in pixels
+const LABEL_WIDTH: f64 = 180.0;
+const BAR_WIDTH: f64 = 300.0;
+const VALUE_WIDTH: f64 = 60.0;
+const ROW_HEIGHT: f64 = 24.0;
+const TITLE_HEIGHT: f64 = 28.0;
+
+/// A bar of a bar chart.
+#[derive(Debug, Clone)]
+pub struct Bar {
+    pub label: String,
+    pub value: f64,
+}
+
+impl Bar {
+    pub fn new(label: impl Into<String>, value: impl Into<f64>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+        }
+    }
+}
+
+// Format the value without the decimals if it is a whole number
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+/// Renders a horizontal bar chart as a standalone SVG element, one bar per row with the label on
+/// the left and the value on the right. The bars are scaled to the largest value.
+pub fn horizontal_bar_chart(title: &str, bars: &[Bar]) -> String {
+    let width = LABEL_WIDTH + BAR_WIDTH + VALUE_WIDTH;
+    let height = TITLE_HEIGHT + ROW_HEIGHT * bars.len() as f64;
+    let max_value = bars.iter().map(|bar| bar.value).fold(0.0, f64::max);
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" role="img" font-family="sans-serif" font-size="12">"#
+    );
+    let _ = write!(
+        svg,
+        r#"<title>{title}</title><text x="0" y="18" font-size="14" font-weight="bold">{title}</text>"#,
+        title = escape(title)
+    );
+
+    for (index, bar) in bars.iter().enumerate() {
+        let y = TITLE_HEIGHT + ROW_HEIGHT * index as f64;
+        let bar_width = if max_value > 0.0 {
+            BAR_WIDTH * bar.value / max_value
+        } else {
+            0.0
+        };
+        let _ = write!(
+            svg,
+            r##"<text x="{label_x}" y="{text_y}" text-anchor="end">{label}</text><rect x="{LABEL_WIDTH}" y="{rect_y}" width="{bar_width:.1}" height="{rect_height}" fill="#2f6fa7"/><text x="{value_x:.1}" y="{text_y}">{value}</text>"##,
+            label_x = LABEL_WIDTH - 8.0,
+            text_y = y + ROW_HEIGHT / 2.0 + 4.0,
+            label = escape(&bar.label),
+            rect_y = y + 4.0,
+            rect_height = ROW_HEIGHT - 8.0,
+            value_x = LABEL_WIDTH + bar_width + 6.0,
+            value = format_value(bar.value),
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+// The layout of the radar charts in pixels
+const RADAR_WIDTH: f64 = 600.0;
+const RADAR_HEIGHT: f64 = 440.0;
+const RADAR_RADIUS: f64 = 150.0;
+const RADAR_LEGEND_HEIGHT: f64 = 50.0;
+
+/// A polygon of a radar chart, with a value from 0 to 100 per axis.
+#[derive(Debug, Clone)]
+pub struct RadarSeries {
+    pub label: String,
+    pub values: Vec<f64>,
+    /// The fill and stroke colour, e.g. `#2f6fa7`.
+    pub color: &'static str,
+    pub dashed: bool,
+}
+
+/// Renders a radar (spider) chart as a standalone SVG element: an axis per label starting at the
+/// top and going clockwise, the grid at every 25 of the 0 to 100 scale, a polygon per series,
+/// and the legend of the series at the bottom.
+pub fn radar_chart(title: &str, axes: &[&str], series: &[RadarSeries]) -> String {
+    let (center_x, center_y) = (RADAR_WIDTH / 2.0, RADAR_HEIGHT / 2.0 + 15.0);
+    let height = RADAR_HEIGHT + RADAR_LEGEND_HEIGHT;
+    // Get the point of the value on the axis, the values over 100 are outside of the grid
+    let point = |axis: usize, value: f64| {
+        let angle = std::f64::consts::TAU * axis as f64 / axes.len() as f64;
+        let distance = RADAR_RADIUS * value / 100.0;
+        (
+            center_x + distance * angle.sin(),
+            center_y - distance * angle.cos(),
+        )
+    };
+    let polygon = |values: &mut dyn Iterator<Item = (usize, f64)>| {
+        values
+            .map(|(axis, value)| {
+                let (x, y) = point(axis, value);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{RADAR_WIDTH}" height="{height}" viewBox="0 0 {RADAR_WIDTH} {height}" role="img" font-family="sans-serif" font-size="12">"#
+    );
+    let _ = write!(
+        svg,
+        r#"<title>{title}</title><text x="{center_x}" y="20" font-size="14" font-weight="bold" text-anchor="middle">{title}</text>"#,
+        title = escape(title)
+    );
+
+    for level in [25.0, 50.0, 75.0, 100.0] {
+        let _ = write!(
+            svg,
+            r##"<polygon points="{}" fill="none" stroke="#ccc"/>"##,
+            polygon(&mut (0..axes.len()).map(|axis| (axis, level)))
+        );
+    }
+    for (axis, label) in axes.iter().enumerate() {
+        let (x, y) = point(axis, 100.0);
+        let (label_x, label_y) = point(axis, 112.0);
+        let anchor = match label_x - center_x {
+            offset if offset > 1.0 => "start",
+            offset if offset < -1.0 => "end",
+            _ => "middle",
+        };
+        let _ = write!(
+            svg,
+            r##"<line x1="{center_x}" y1="{center_y}" x2="{x:.1}" y2="{y:.1}" stroke="#ccc"/><text x="{label_x:.1}" y="{:.1}" text-anchor="{anchor}">{}</text>"##,
+            label_y + 4.0,
+            escape(label)
+        );
+    }
+
+    for (index, series) in series.iter().enumerate() {
+        let dash = if series.dashed {
+            r#" stroke-dasharray="4 3""#
+        } else {
+            ""
+        };
+        let _ = write!(
+            svg,
+            r#"<polygon points="{}" fill="{color}" fill-opacity="0.25" stroke="{color}" stroke-width="2"{dash}/>"#,
+            polygon(
+                &mut series
+                    .values
+                    .iter()
+                    .map(|value| value.clamp(0.0, 100.0))
+                    .enumerate()
+            ),
+            color = series.color,
+        );
+        // The legend entries side by side below the chart
+        let legend_x = 20.0 + 220.0 * index as f64;
+        let legend_y = RADAR_HEIGHT + 20.0;
+        let _ = write!(
+            svg,
+            r#"<rect x="{legend_x}" y="{}" width="12" height="12" fill="{color}" fill-opacity="0.5" stroke="{color}"/><text x="{}" y="{legend_y}">{}</text>"#,
+            legend_y - 10.0,
+            legend_x + 18.0,
+            escape(&series.label),
+            color = series.color,
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
@@ -0,0 +1,66 @@
+use std::{error::Error, fs, path::Path};
+
+use minijinja::{Environment, UndefinedBehavior, Value};
+
+use super::season_report::SeasonReport;
+
+// The templates shipped with the application, by the name used in the command line. The names of
+// the sources end with the extension of the output, which decides the escaping (none for these).
+const BUILTIN_TEMPLATES: [(&str, &str, &str); 3] = [
+    ("slack", "slack.txt", include_str!("templates/slack.txt.j2")),
+    ("email", "email.txt", include_str!("templates/email.txt.j2")),
+    (
+        "markdown",
+        "markdown.md",
+        include_str!("templates/markdown.md.j2"),
+    ),
+];
+
+/// Returns the names of the built-in templates.
+pub fn builtin_template_names() -> Vec<&'static str> {
+    BUILTIN_TEMPLATES.iter().map(|(name, _, _)| *name).collect()
+}
+
+/// Renders the report with a Jinja template: a template file, or a built-in template by its name
+/// (see [`builtin_template_names`]). The file takes precedence over a built-in template with the
+/// same name.
+///
+/// The template gets the fields of [`SeasonReport`] as the variables. The values of a template
+/// whose file name ends with `.html`, `.htm`, or `.xml` (optionally followed by `.j2`) are
+/// HTML-escaped. Using an
+/// undefined variable is an error, so the typos do not go unnoticed.
+pub fn render_template(template: &str, report: &SeasonReport) -> Result<String, Box<dyn Error>> {
+    let path = Path::new(template);
+    let (name, source) = if path.is_file() {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        (name, fs::read_to_string(path)?)
+    } else {
+        let (_, name, source) = BUILTIN_TEMPLATES
+            .iter()
+            .find(|(builtin, _, _)| *builtin == template)
+            .ok_or_else(|| {
+                format!(
+                    "Template {} is neither a file nor a built-in template ({})",
+                    template,
+                    builtin_template_names().join(", ")
+                )
+            })?;
+        (name.to_string(), source.to_string())
+    };
+
+    let mut environment = Environment::new();
+    environment.set_trim_blocks(true);
+    environment.set_lstrip_blocks(true);
+    environment.set_keep_trailing_newline(true);
+    environment.set_undefined_behavior(UndefinedBehavior::Strict);
+    environment.add_template_owned(name.clone(), source)?;
+
+    let output = environment
+        .get_template(&name)?
+        .render(Value::from_serialize(report))?;
+
+    Ok(output)
+}
@@ -0,0 +1,62 @@
+mod server_handlers;
+mod server_state;
+
+use std::{error::Error, net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{routing::get, Router};
+use reqwest_middleware::ClientWithMiddleware;
+use sportradar::SportradarClient;
+
+use self::server_state::ServerState;
+
+/// Runs the HTTP server exposing the competitions, seasons, and leaderboards as JSON.
+///
+/// The endpoints are:
+///
+/// * `GET /competitions` - the allowed competitions of the sport.
+/// * `GET /competitions/{id}/seasons` - the enabled seasons of the competition.
+/// * `GET /seasons/{id}/leaders?metric=goals&limit=10` - the season leaderboard of the metric
+///   (`goals` or `assists`), of 1 to 100 players.
+/// * `GET /players/{id}?season={season_id}` - the player's season statistics. Without the `season`
+///   parameter the player is looked up in all the seasons loaded by the server.
+///
+/// The resources missing in the API respond with 404, the other API failures with 502. The error
+/// responses have a generic message, the details of the failure are logged with the API key
+/// redacted.
+///
+/// The fetched data is cached in memory for `cache_ttl` and shared by all the clients, and the
+/// concurrent requests for the same data are coalesced into a single fetch. The failed fetches
+/// and the seasons whose competitors were not all fetched are not cached, and each cache keeps
+/// the 32 most recent keys at most. All the API requests of the server share the rate limit of the
+/// API key (see [`sportradar::ApiConfig::rate_limit`]).
+pub async fn run_server(
+    client: SportradarClient<ClientWithMiddleware>,
+    address: SocketAddr,
+    cache_ttl: Duration,
+) -> Result<(), Box<dyn Error>> {
+    let state = Arc::new(ServerState::new(client, cache_ttl).await?);
+
+    let router = Router::new()
+        .route("/competitions", get(server_handlers::competitions))
+        .route(
+            "/competitions/{id}/seasons",
+            get(server_handlers::competition_seasons),
+        )
+        .route(
+            "/seasons/{id}/leaders",
+            get(server_handlers::season_leaders),
+        )
+        .route("/players/{id}", get(server_handlers::player))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    println!("SportRadar server is listening on http://{}", address);
+    // Stop on Ctrl+C, so that the diagnostics report can be written
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await?;
+
+    Ok(())
+}
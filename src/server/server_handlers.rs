@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use sportradar::{
+    enums::Players,
+    models::{CompetitionSeason, Player},
+    services::AppStateService,
+};
+
+use super::server_state::{FetchError, ServerState};
+
+type SharedState = State<Arc<ServerState>>;
+
+// The default and the largest number of the players of a leaderboard
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 100;
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+fn error_response(status: StatusCode, error: impl Into<String>) -> Response {
+    let error = error.into();
+    (status, Json(ErrorResponse { error })).into_response()
+}
+
+// A resource missing in the API is missing in the server too, the other failures are the API's.
+// The details of the failure are logged only, the clients get a generic message.
+fn fetch_error_response(err: FetchError) -> Response {
+    tracing::warn!(status = ?err.status, error = %err.message, "API request failed");
+    match err.status {
+        Some(StatusCode::NOT_FOUND) => error_response(
+            StatusCode::NOT_FOUND,
+            "The resource was not found in the Sportradar API",
+        ),
+        _ => error_response(StatusCode::BAD_GATEWAY, "The Sportradar API request failed"),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeadersQuery {
+    metric: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct Leader {
+    rank: usize,
+    value: u8,
+    player: Player,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayerQuery {
+    season: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayerSeason {
+    season: Option<CompetitionSeason>,
+    player: Player,
+}
+
+pub async fn competitions(State(state): SharedState) -> Response {
+    match state.competitions().await {
+        Ok(competitions) => Json(competitions.as_ref().clone()).into_response(),
+        Err(err) => fetch_error_response(err),
+    }
+}
+
+pub async fn competition_seasons(
+    State(state): SharedState,
+    Path(competition_id): Path<String>,
+) -> Response {
+    match state.seasons(&competition_id).await {
+        Ok(Some(seasons)) => Json(seasons.as_ref().clone()).into_response(),
+        Ok(None) => error_response(
+            StatusCode::NOT_FOUND,
+            format!("Competition {} not found", competition_id),
+        ),
+        Err(err) => fetch_error_response(err),
+    }
+}
+
+pub async fn season_leaders(
+    State(state): SharedState,
+    Path(season_id): Path<String>,
+    Query(query): Query<LeadersQuery>,
+) -> Response {
+    let metric: Players = match query.metric.as_deref().unwrap_or("goals").parse() {
+        Ok(metric) => metric,
+        Err(err) => return error_response(StatusCode::BAD_REQUEST, err),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let app = match state.season_state(&season_id).await {
+        Ok(app) => app,
+        Err(err) => return fetch_error_response(err),
+    };
+
+    let leaders: Vec<Leader> = app
+        .get_top_players(metric, limit)
+        .into_iter()
+        .enumerate()
+        .map(|(index, player)| Leader {
+            rank: index + 1,
+            value: metric.season_value(&player),
+            player,
+        })
+        .collect();
+
+    Json(leaders).into_response()
+}
+
+pub async fn player(
+    State(state): SharedState,
+    Path(player_id): Path<String>,
+    Query(query): Query<PlayerQuery>,
+) -> Response {
+    let season_states = match query.season {
+        Some(season_id) => match state.season_state(&season_id).await {
+            Ok(app) => vec![app],
+            Err(err) => return fetch_error_response(err),
+        },
+        None => state.loaded_season_states(),
+    };
+
+    let player_seasons: Vec<PlayerSeason> = season_states
+        .iter()
+        .filter_map(|app| {
+            Some(PlayerSeason {
+                season: app.selected_season(),
+                player: app.players().get(&player_id)?.clone(),
+            })
+        })
+        .collect();
+
+    if player_seasons.is_empty() {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("Player {} not found in the loaded seasons", player_id),
+        );
+    }
+
+    Json(player_seasons).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use sportradar::{ApiConfig, SportradarClientBuilder};
+
+    use super::*;
+
+    const API_KEY: &str = "secret-test-api-key";
+
+    fn local_state_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("sportradar_server_test_{}", std::process::id()))
+    }
+
+    // Get the server state whose API requests fail to connect
+    async fn unreachable_api_state() -> Arc<ServerState> {
+        let dir = local_state_dir();
+        let config = ApiConfig {
+            api_key: API_KEY.to_string(),
+            // Nothing listens on the port 1, so the connection is refused
+            base_url: "http://127.0.0.1:1".to_string(),
+            quota_file: dir.join("quota.json"),
+            cache_dir: dir,
+            ..ApiConfig::default()
+        };
+        let client = SportradarClientBuilder::new()
+            .config(config)
+            .max_retries(0)
+            .build();
+
+        Arc::new(
+            ServerState::new(client, Duration::from_secs(60))
+                .await
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn failed_request_does_not_leak_the_api_key() {
+        let state = unreachable_api_state().await;
+
+        let response = competitions(State(state)).await;
+        let _ = std::fs::remove_dir_all(local_state_dir());
+
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8_lossy(&body);
+        assert!(!body.contains(API_KEY), "{}", body);
+        assert!(!body.contains("api_key"), "{}", body);
+        assert_eq!(body, r#"{"error":"The Sportradar API request failed"}"#);
+    }
+
+    #[test]
+    fn fetch_error_message_has_no_api_key() {
+        let err: Box<dyn std::error::Error> =
+            format!("error sending request for url (http://mock/a.json?api_key={API_KEY})").into();
+
+        let err = FetchError::from(err);
+
+        assert_eq!(err.status, None);
+        assert!(!err.message.contains(API_KEY));
+    }
+}
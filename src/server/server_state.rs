@@ -0,0 +1,383 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use reqwest::StatusCode;
+use reqwest_middleware::ClientWithMiddleware;
+use sportradar::{client::RateLimitedClient, SportradarClient};
+use tokio::sync::OnceCell;
+
+use sportradar::{
+    helpers::redact_api_key,
+    models::{AppState, Competition, CompetitionSeason, FetchProgress, Sport},
+    services::{AppStateService, BaseInfoService, FetchProgressService},
+};
+
+/// A failed fetch from the API, keeping the status of the API response (if the API responded) so
+/// the server can tell a missing resource from an unavailable API. The message is for the server
+/// log only, with the API key redacted.
+#[derive(Debug, Clone)]
+pub struct FetchError {
+    pub status: Option<StatusCode>,
+    pub message: String,
+}
+
+impl From<Box<dyn Error>> for FetchError {
+    fn from(err: Box<dyn Error>) -> Self {
+        Self {
+            status: err
+                .downcast_ref::<reqwest::Error>()
+                .and_then(reqwest::Error::status),
+            message: redact_api_key(&err.to_string()),
+        }
+    }
+}
+
+// The largest number of the keys kept in each cache, so that the requests for arbitrary ids do not
+// grow the caches without a limit
+const CACHE_CAPACITY: usize = 32;
+
+/// A loaded value, which is cached only if it is complete.
+enum Loaded<T> {
+    Complete(T),
+    /// A value with some of its data missing, e.g. a season whose competitors were not all
+    /// fetched. It is returned to the requests waiting for it, but the next request loads it again.
+    Partial(T),
+}
+
+/// A cached value together with the time it was created. The `OnceCell` lets the concurrent
+/// requests for the same key wait for a single fetch instead of starting their own.
+struct CacheEntry<T> {
+    created_at: Instant,
+    cell: Arc<OnceCell<T>>,
+}
+
+/// An in-memory cache shared by all the server requests, keeping at most [`CACHE_CAPACITY`] keys.
+struct SharedCache<T> {
+    entries: Mutex<HashMap<String, CacheEntry<T>>>,
+    ttl: Duration,
+}
+
+impl<T: Clone> SharedCache<T> {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Returns the cached value of the key, loading it if it is missing or expired. If the value
+    /// is already being loaded by another request, waits for that load to finish.
+    ///
+    /// A failed or partial load is not cached, its key is removed so the next request tries again.
+    /// The expired keys are removed on every lookup, and the oldest key makes room for a new one
+    /// when the cache is full.
+    async fn get_or_load<F, Fut>(&self, key: &str, load: F) -> Result<T, FetchError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Loaded<T>, FetchError>>,
+    {
+        let cell = {
+            let mut entries = self.entries.lock().expect("Cache lock is poisoned");
+            entries.retain(|_, entry| entry.created_at.elapsed() <= self.ttl);
+            if !entries.contains_key(key) && entries.len() >= CACHE_CAPACITY {
+                // The requests waiting for the removed entry keep its cell
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.created_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+
+            entries
+                .entry(key.to_string())
+                .or_insert_with(|| CacheEntry {
+                    created_at: Instant::now(),
+                    cell: Arc::new(OnceCell::new()),
+                })
+                .cell
+                .clone()
+        };
+
+        tracing::debug!(key, hit = cell.initialized(), "Cache lookup");
+        let mut partial = None;
+        let partial_slot = &mut partial;
+        let loaded = cell
+            .get_or_try_init(|| async move {
+                match load().await.map_err(Some)? {
+                    Loaded::Complete(value) => Ok(value),
+                    Loaded::Partial(value) => {
+                        *partial_slot = Some(value);
+                        Err(None)
+                    }
+                }
+            })
+            .await
+            .cloned();
+
+        match (loaded, partial) {
+            (Ok(value), _) => Ok(value),
+            (Err(err), partial) => {
+                self.remove_unloaded(key, &cell);
+                partial.ok_or_else(|| err.expect("A load without a value failed"))
+            }
+        }
+    }
+
+    // Remove the entry of the key if it is still the given unloaded one
+    fn remove_unloaded(&self, key: &str, cell: &Arc<OnceCell<T>>) {
+        let mut entries = self.entries.lock().expect("Cache lock is poisoned");
+        if entries
+            .get(key)
+            .is_some_and(|entry| Arc::ptr_eq(&entry.cell, cell) && !cell.initialized())
+        {
+            entries.remove(key);
+        }
+    }
+
+    /// Returns all the values loaded so far.
+    fn loaded_values(&self) -> Vec<T> {
+        let entries = self.entries.lock().expect("Cache lock is poisoned");
+        entries
+            .values()
+            .filter_map(|entry| entry.cell.get().cloned())
+            .collect()
+    }
+}
+
+/// Logs a summary after the statistics of a season are fetched, the server has no terminal to draw
+/// the progress on, and keeps the summary to tell whether all the competitors were fetched.
+#[derive(Debug, Default)]
+struct FetchProgressLogger {
+    last: Mutex<FetchProgress>,
+}
+
+impl FetchProgressLogger {
+    fn last(&self) -> FetchProgress {
+        *self.last.lock().expect("Progress lock is poisoned")
+    }
+}
+
+impl FetchProgressService for FetchProgressLogger {
+    fn on_progress(&self, _progress: &FetchProgress) {}
+
+    fn on_finish(&self, progress: &FetchProgress) {
+        *self.last.lock().expect("Progress lock is poisoned") = *progress;
+        tracing::info!(
+            fetched = progress.completed - progress.failed,
+            competitors = progress.total,
+            failed = progress.failed,
+            retries = progress.retries,
+            "Fetched the competitor statistics"
+        );
+    }
+}
+
+/// The HTTP client of the server: all the requests of the server share the rate limit of the API
+/// key, so the concurrent fetches of different seasons do not go over it together.
+type ServerHttpClient = RateLimitedClient<ClientWithMiddleware>;
+
+/// The state shared by all the server requests: the HTTP client, the sport, and the caches of the
+/// fetched data.
+pub struct ServerState {
+    client: SportradarClient<ServerHttpClient>,
+    sport: Sport,
+    competitions: SharedCache<Arc<Vec<Competition>>>,
+    seasons: SharedCache<Arc<Vec<CompetitionSeason>>>,
+    season_states: SharedCache<Arc<AppState<ServerHttpClient>>>,
+}
+
+impl ServerState {
+    pub async fn new(
+        client: SportradarClient<ClientWithMiddleware>,
+        cache_ttl: Duration,
+    ) -> Result<Self, Box<dyn Error>> {
+        let sport = client.sport()?;
+        let config = client.config().clone();
+        let http_client = RateLimitedClient::new(client.http_client().clone(), config.rate_limit);
+
+        Ok(Self {
+            client: SportradarClient::new(http_client, config),
+            sport,
+            competitions: SharedCache::new(cache_ttl),
+            seasons: SharedCache::new(cache_ttl),
+            season_states: SharedCache::new(cache_ttl),
+        })
+    }
+
+    // Get a new app state with the server's sport selected
+    fn new_app_state(&self) -> AppState<ServerHttpClient> {
+        let mut app = self.client.app_state();
+        app.set_selected_sport(&self.sport);
+        app
+    }
+
+    /// Returns the allowed competitions of the sport.
+    pub async fn competitions(&self) -> Result<Arc<Vec<Competition>>, FetchError> {
+        self.competitions
+            .get_or_load("competitions", || async {
+                let mut app = self.new_app_state();
+                app.on_sport_select().await.map_err(FetchError::from)?;
+                Ok(Loaded::Complete(Arc::new(
+                    app.competitions().iter().cloned().collect(),
+                )))
+            })
+            .await
+    }
+
+    /// Returns the enabled seasons of the competition, or `None` if there is no such allowed
+    /// competition.
+    pub async fn seasons(
+        &self,
+        competition_id: &str,
+    ) -> Result<Option<Arc<Vec<CompetitionSeason>>>, FetchError> {
+        let competitions = self.competitions().await?;
+        let Some(competition) = competitions
+            .iter()
+            .find(|competition| competition.id() == competition_id)
+        else {
+            return Ok(None);
+        };
+
+        self.seasons
+            .get_or_load(competition_id, || async {
+                let mut app = self.new_app_state();
+                app.set_selected_competition(competition);
+                app.on_competition_select()
+                    .await
+                    .map_err(FetchError::from)?;
+                Ok(Loaded::Complete(Arc::new(app.seasons().to_vec())))
+            })
+            .await
+            .map(Some)
+    }
+
+    /// Returns the app state with all the data of the season loaded. The season is fetched once
+    /// for all the concurrent requests. A season whose competitors were not all fetched is
+    /// returned without being cached or saved, so the next request fetches it again.
+    pub async fn season_state(
+        &self,
+        season_id: &str,
+    ) -> Result<Arc<AppState<ServerHttpClient>>, FetchError> {
+        self.season_states
+            .get_or_load(season_id, || async {
+                let mut app = self.new_app_state();
+                // Only the season id is needed to fetch the season data, the name is known only
+                // if the competition's seasons were already requested
+                let season = self
+                    .seasons
+                    .loaded_values()
+                    .iter()
+                    .flat_map(|seasons| seasons.iter())
+                    .find(|season| season.id() == season_id)
+                    .cloned()
+                    .unwrap_or_else(|| CompetitionSeason {
+                        id: season_id.to_string(),
+                        name: season_id.to_string(),
+                        disabled: false,
+                    });
+                let progress = Arc::new(FetchProgressLogger::default());
+                app.set_selected_season(season);
+                app.set_progress_observer(progress.clone());
+                app.on_season_select().await.map_err(FetchError::from)?;
+                if progress.last().failed > 0 {
+                    return Ok(Loaded::Partial(Arc::new(app)));
+                }
+
+                if let Err(err) = app.save_season_snapshot() {
+                    tracing::warn!(error = %err, "Failed to save the season to the local cache");
+                }
+                Ok(Loaded::Complete(Arc::new(app)))
+            })
+            .await
+    }
+
+    /// Returns all the seasons loaded by the server so far.
+    pub fn loaded_season_states(&self) -> Vec<Arc<AppState<ServerHttpClient>>> {
+        self.season_states.loaded_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed() -> FetchError {
+        FetchError {
+            status: Some(StatusCode::NOT_FOUND),
+            message: "Not found".to_string(),
+        }
+    }
+
+    fn keys(cache: &SharedCache<u32>) -> usize {
+        cache.entries.lock().unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn cache_keeps_only_complete_values() {
+        let cache = SharedCache::new(Duration::from_secs(60));
+
+        let partial = cache
+            .get_or_load("season", || async { Ok(Loaded::Partial(1)) })
+            .await;
+        let complete = cache
+            .get_or_load("season", || async { Ok(Loaded::Complete(2)) })
+            .await;
+        let cached = cache
+            .get_or_load("season", || async { Ok(Loaded::Complete(3)) })
+            .await;
+
+        assert_eq!(partial.unwrap(), 1);
+        assert_eq!(complete.unwrap(), 2);
+        assert_eq!(cached.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn cache_removes_failed_and_expired_keys() {
+        let cache = SharedCache::new(Duration::ZERO);
+
+        let err = cache
+            .get_or_load("unknown", || async { Err(failed()) })
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, Some(StatusCode::NOT_FOUND));
+        assert_eq!(keys(&cache), 0);
+
+        cache
+            .get_or_load("season", || async { Ok(Loaded::Complete(1)) })
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        let reloaded = cache
+            .get_or_load("other", || async { Ok(Loaded::Complete(2)) })
+            .await;
+        assert_eq!(reloaded.unwrap(), 2);
+        assert_eq!(keys(&cache), 1);
+    }
+
+    #[tokio::test]
+    async fn cache_keeps_at_most_its_capacity() {
+        let cache = SharedCache::new(Duration::from_secs(60));
+
+        for value in 0..CACHE_CAPACITY as u32 + 5 {
+            cache
+                .get_or_load(
+                    &value.to_string(),
+                    || async move { Ok(Loaded::Complete(value)) },
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(keys(&cache), CACHE_CAPACITY);
+        // The oldest keys make room for the new ones
+        assert!(!cache.entries.lock().unwrap().contains_key("0"));
+        assert!(cache.entries.lock().unwrap().contains_key("36"));
+    }
+}
@@ -1,53 +1,126 @@
-use std::error::Error;
+use std::{error::Error, time::Instant};
 
 use serde::de::DeserializeOwned;
+use tracing::{field, Instrument, Span};
 
+use crate::{
+    enums::ResponseFormat,
+    helpers::{
+        record_api_request, record_response_diagnostics, scrub_api_key, scrub_api_key_from_error,
+    },
+};
+
+/// The HTTP client used to fetch the data from the Sportradar API.
+#[allow(async_fn_in_trait)]
 pub trait ApiService: Send {
+    /// Fetches the URL and returns the raw response body. A response with an error status is
+    /// returned as an error (a [`reqwest::Error`] with the status for the HTTP clients). The
+    /// errors of the HTTP clients leave out the URL, which has the API key in its query.
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>>;
+
+    /// Fetches the URL and deserializes the response body with the deserializer of the given
+    /// format (JSON or XML).
+    ///
+    /// The list items which do not match the models are skipped, and the differences between the
+    /// response and the models are added to the [`crate::helpers::diagnostics_report`].
+    ///
+    /// The call runs in the `api_request` span recording the endpoint (the URL without the API
+    /// key, which is also scrubbed from the errors), the response status, the latency, the number of retries, the cache hit or miss (of
+    /// the clients serving the responses from a cache), and the payload size.
+    async fn get_data<Response>(
+        &self,
+        url: impl Into<String>,
+        format: ResponseFormat,
+    ) -> Result<Response, Box<dyn Error>>
+    where
+        Response: DeserializeOwned,
+    {
+        let url = url.into();
+        let endpoint = scrub_api_key(&url);
+        let span = tracing::debug_span!(
+            "api_request",
+            endpoint = %endpoint,
+            %format,
+            status = field::Empty,
+            latency_ms = field::Empty,
+            retries = 0,
+            cache = field::Empty,
+            payload_bytes = field::Empty,
+        );
+
+        async move {
+            let started_at = Instant::now();
+            let body = self.get_text(url).await.map_err(scrub_api_key_from_error);
+            let span = Span::current();
+            span.record("latency_ms", started_at.elapsed().as_millis() as u64);
+            let body = body.inspect_err(|err| tracing::warn!(error = %err, "API call failed"))?;
+            span.record("payload_bytes", body.len());
+
+            let (data, diagnostics) = format
+                .deserialize_with_diagnostics(&body)
+                .inspect_err(|err| tracing::warn!(error = %err, "Failed to parse the response"))?;
+
+            if !diagnostics.is_empty() {
+                if !diagnostics.skipped_items.is_empty() {
+                    tracing::warn!(
+                        skipped_items = diagnostics.skipped_items.len(),
+                        "Skipped the items which could not be parsed"
+                    );
+                }
+                record_response_diagnostics(endpoint, diagnostics);
+            }
+
+            Ok(data)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Fetches the URL and deserializes the JSON response body.
     async fn get_json_data<Response>(
         &self,
         url: impl Into<String>,
     ) -> Result<Response, Box<dyn Error>>
     where
-        Response: DeserializeOwned;
+        Response: DeserializeOwned,
+    {
+        self.get_data(url, ResponseFormat::Json).await
+    }
 }
 
 impl ApiService for reqwest::Client {
-    async fn get_json_data<Response: DeserializeOwned>(
-        &self,
-        url: impl Into<String>,
-    ) -> Result<Response, Box<dyn Error>> {
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>> {
         let url = url.into();
-        match self.get(url.clone()).send().await {
-            Ok(response) => match response.json::<Response>().await {
-                Ok(json) => Ok(json),
-                Err(e) => {
-                    eprintln!("Call to this API url failed: {}", url);
-                    Err(Box::new(e))
+        record_api_request(&url);
+        let body = match self.get(url).send().await {
+            Ok(response) => {
+                Span::current().record("status", response.status().as_u16());
+                match response.error_for_status() {
+                    Ok(response) => response.text().await,
+                    Err(err) => Err(err),
                 }
-            },
-            Err(e) => {
-                eprintln!("Call to this API url failed: {}", url);
-                Err(Box::new(e))
             }
-        }
+            Err(e) => Err(e),
+        };
+
+        // The URL of the error has the API key in its query, so it is left out
+        body.map_err(|err| err.without_url().into())
     }
 }
 
 impl ApiService for reqwest_middleware::ClientWithMiddleware {
-    async fn get_json_data<Response: DeserializeOwned>(
-        &self,
-        url: impl Into<String>,
-    ) -> Result<Response, Box<dyn Error>> {
-        let url = url.into();
-        match self.get(url.clone()).send().await {
-            Ok(response) => match response.json::<Response>().await {
-                Ok(json) => Ok(json),
-                Err(e) => {
-                    println!("Call to this API url failed: {}", url);
-                    Err(Box::new(e))
-                }
-            },
-            Err(e) => Err(Box::new(e)),
+    async fn get_text(&self, url: impl Into<String>) -> Result<String, Box<dyn Error>> {
+        match self.get(url.into()).send().await {
+            Ok(response) => {
+                Span::current().record("status", response.status().as_u16());
+                // The URL of the errors has the API key in its query, so it is left out
+                let response = response
+                    .error_for_status()
+                    .map_err(|err| err.without_url())?;
+                Ok(response.text().await.map_err(|err| err.without_url())?)
+            }
+            Err(reqwest_middleware::Error::Reqwest(e)) => Err(Box::new(e.without_url())),
+            Err(e) => Err(scrub_api_key_from_error(Box::new(e))),
         }
     }
 }
@@ -1,26 +1,31 @@
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
+    sync::Arc,
 };
 
 use itertools::Itertools;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use tracing::{field, Span};
 
 use crate::{
     api_responses::{
         CompetitionSeasonsApiResponse, CompetitionsApiResponse, CompetitorStatisticsApiResponse,
         SchedulesApiResponse,
     },
-    enums::Players,
+    config::ApiConfig,
+    enums::{Endpoint, Players},
     helpers::{
-        get_allowed_competitions, get_allowed_countries, get_api_url, prompt_boolean,
-        prompt_number, prompt_select,
+        check_quota_budget, flush_quota_usage, now_utc, rank_matches, save_season_snapshot,
+        scrub_api_key, top_players, ProgressTracker,
     },
+    models::{PlannedRequest, RequestPlan, SeasonSnapshot},
 };
 
 use super::{
     ApiService, BaseInfoService, CompetitorsService, ConstructService, CountryService,
-    PlayerSeasonStatisticsService, PlayerService, PlayerStatisticsService, SeasonService,
+    FetchProgressService, PlayerSeasonStatisticsService, PlayerService, PlayerStatisticsService,
+    SeasonService, SportApiService, SportEventStatusService,
 };
 
 /// The `AppStateService` trait defines the interface for managing the application state, including sports,
@@ -34,8 +39,12 @@ use super::{
 /// selection of sports, competitions, seasons, and player statistics. It also includes methods for fetching and
 /// managing the data required for the application, such as competitors and players.
 ///
-/// The `run()` method is the main entry point for the application, which guides the user through the process of
-/// selecting a sport, competition, season, and player statistics to display.
+/// The trait never prompts the user and never prints the results, so the same state and the same fetch pipeline
+/// can be used by any front end: the interactive wizard, the terminal dashboard, or the HTTP server.
+///
+/// The returned futures are `Send` whenever the implementing type and the HTTP client are, which holds for
+/// [`crate::models::AppState`] with the `reqwest` clients.
+#[allow(async_fn_in_trait)]
 pub trait AppStateService<
     HttpClient,
     Sport,
@@ -47,11 +56,16 @@ pub trait AppStateService<
     PlayerStatistics,
 > where
     HttpClient: ApiService,
-    Sport: BaseInfoService + ConstructService,
+    Sport: BaseInfoService + SportApiService,
     Competition: BaseInfoService + CountryService,
     Season: BaseInfoService + SeasonService,
-    SportEvent:
-        CompetitorsService<Competitor> + for<'de> Deserialize<'de> + std::cmp::Eq + std::hash::Hash,
+    SportEvent: CompetitorsService<Competitor>
+        + SportEventStatusService
+        + for<'de> Deserialize<'de>
+        + Serialize
+        + Clone
+        + std::cmp::Eq
+        + std::hash::Hash,
     Competitor: BaseInfoService + ConstructService,
     Player: BaseInfoService
         + PlayerStatisticsService
@@ -64,11 +78,13 @@ pub trait AppStateService<
     // Get the HTTP client
     fn get_http_client(&self) -> &HttpClient;
 
-    #[allow(unused)]
+    // Get the API settings used to build the request URLs and to filter the fetched data
+    fn api_config(&self) -> &ApiConfig;
+
     fn set_sports(&mut self, sports: HashSet<Sport>);
 
     // Get available sports
-    fn sports(&self) -> HashSet<Sport>;
+    fn sports(&self) -> &HashSet<Sport>;
 
     // Set selected sport
     fn set_selected_sport(&mut self, sport: &Sport);
@@ -80,7 +96,7 @@ pub trait AppStateService<
     fn set_competitions(&mut self, competitions: HashSet<Competition>);
 
     // Get available competitions
-    fn competitions(&self) -> HashSet<Competition>;
+    fn competitions(&self) -> &HashSet<Competition>;
 
     // Set selected competition
     fn set_selected_competition(&mut self, competition: &Competition);
@@ -92,7 +108,7 @@ pub trait AppStateService<
     fn set_seasons(&mut self, seasons: Vec<Season>);
 
     // Get available seasons
-    fn seasons(&self) -> Vec<Season>;
+    fn seasons(&self) -> &[Season];
 
     // Set selected Season
     fn set_selected_season(&mut self, season: Season);
@@ -104,20 +120,25 @@ pub trait AppStateService<
     fn set_sport_events(&mut self, events: HashSet<SportEvent>);
 
     // Get sports events
-    #[allow(unused)]
-    fn sport_events(&self) -> HashSet<SportEvent>;
+    fn sport_events(&self) -> &HashSet<SportEvent>;
 
     // Set competitors and fetch
     fn set_competitors(&mut self, competitors: HashSet<Competitor>);
 
     // Get competitors
-    fn competitors(&self) -> HashSet<Competitor>;
+    fn competitors(&self) -> &HashSet<Competitor>;
 
     // Set players
     fn set_players(&mut self, players: HashMap<String, Player>);
 
     // Get players
-    fn players(&self) -> HashMap<String, Player>;
+    fn players(&self) -> &HashMap<String, Player>;
+
+    // Get the observer of the bulk fetches (if any)
+    fn progress_observer(&self) -> Option<Arc<dyn FetchProgressService>>;
+
+    // Set the observer of the bulk fetches, e.g. a progress bar
+    fn set_progress_observer(&mut self, observer: Arc<dyn FetchProgressService>);
 
     fn selected_player_statistics(&self) -> Option<Players>;
 
@@ -131,23 +152,37 @@ pub trait AppStateService<
 
     fn reset(&mut self);
 
+    // Clear the selected competition together with its seasons and the selected season's data
+    fn reset_selected_competition(&mut self);
+
+    // Clear the selected season together with its sport events, competitors, and players
+    fn reset_selected_season(&mut self);
+
     // ==================== METHODS FOR FREE ====================
 
     /// The `on_start` method is called when the application is started.
-    /// It constructs a default `Sport` instance and returns it as a `HashSet` of sports.
-    /// The fetching of sports is mocked in this example.
+    /// It returns the sports whose APIs are listed in the API config (see [`ApiConfig::sports`])
+    /// and saves them to the application state.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the API config lists no sports.
+    #[tracing::instrument(skip_all)]
     async fn on_start(&mut self) -> Result<HashSet<Sport>, Box<dyn Error>> {
-        let sports = if self.sports().is_empty() {
-            // Mock fetching sports
-            let mut set = HashSet::<Sport>::new();
-            let sport = Sport::construct("sr:sport:1".to_string(), "Soccer".to_string());
-            set.insert(sport);
-            set
-        } else {
-            self.sports()
-        };
+        if self.sports().is_empty() {
+            let sports: HashSet<Sport> = self
+                .api_config()
+                .sports
+                .iter()
+                .map(Sport::from_sport_api)
+                .collect();
+            if sports.is_empty() {
+                return Err("No sports are configured".into());
+            }
+            self.set_sports(sports);
+        }
 
-        Ok(sports)
+        Ok(self.sports().clone())
     }
 
     /// The `on_sport_select` method is called when a sport is selected.
@@ -161,44 +196,47 @@ pub trait AppStateService<
     /// 2. Sets the selected sport in the application state.
     /// 3. Constructs the API URL for fetching the competitions.
     /// 4. Retrieves the HTTP client from the application state.
-    /// 5. Fetches the list of allowed competitions and countries from the environment variables.
-    /// 6. Fetches the list of competitions for the selected sport from the API.
-    /// 7. Filters the competitions based on the allowed competitions and countries.
-    /// 8. Sets the filtered competitions in the application state.
+    /// 5. Fetches the list of competitions for the selected sport from the API.
+    /// 6. Filters the competitions based on the allowed competitions and countries of the sport API.
+    /// 7. Sets the filtered competitions in the application state.
     ///
     /// # Errors
     ///
     /// This method can return an error if there is a problem fetching the competitions from the API
     /// or setting the competitions in the application state.
+    #[tracing::instrument(skip_all, fields(sport = field::Empty, competitions = field::Empty))]
     async fn on_sport_select(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let sport = self
             .selected_sport()
             .expect("No selected sport to fetch competitions");
+        Span::current().record("sport", sport.id());
 
         self.set_selected_sport(&sport);
-        let api_url = get_api_url(&sport, "competitions");
+        self.check_budget(1)?;
+        let api_url = self.api_config().api_url(&sport, &Endpoint::Competitions);
         let client = self.get_http_client();
-
-        // Get the list of allowed competitions and countries from the environment variables
-        let allowed_competitions = get_allowed_competitions();
-        let allowed_countries = get_allowed_countries();
+        let format = self.api_config().format;
 
         // Fetch the competitions for the selected sport
         let response = client
-            .get_json_data::<CompetitionsApiResponse<Competition>>(api_url)
-            .await?;
+            .get_data::<CompetitionsApiResponse<Competition>>(api_url, format)
+            .await;
+        flush_quota_usage(&self.api_config().quota_file);
+        let response = response?;
 
-        let competitions = response
+        let competitions: HashSet<Competition> = response
             .competitions
             .into_iter()
             // Filter out competitions for which the `league_timeline` stats are not available
             .filter(|competition: &Competition| {
-                allowed_competitions.contains(&competition.name())
-                    && allowed_countries.contains(&competition.country_name())
+                sport
+                    .sport_api()
+                    .is_competition_allowed(&competition.name(), &competition.country_name())
             })
             .collect();
 
         // Set competitions to the state
+        Span::current().record("competitions", competitions.len());
         self.set_competitions(competitions);
         Ok(())
     }
@@ -210,6 +248,7 @@ pub trait AppStateService<
     ///
     /// This method can return an error if there is a problem fetching the seasons from the API or
     /// setting the seasons in the application state.
+    #[tracing::instrument(skip_all, fields(competition = field::Empty, seasons = field::Empty))]
     async fn on_competition_select(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let sport = self
             .selected_sport()
@@ -217,17 +256,27 @@ pub trait AppStateService<
         let competition = self
             .selected_competition()
             .expect("No selected competition to fetch seasons");
+        Span::current().record("competition", competition.id());
+        self.check_budget(1)?;
 
         let client = self.get_http_client();
-        let api_url = get_api_url(&sport, format!("competitions/{}/seasons", competition.id()));
+        let format = self.api_config().format;
+        let api_url = self.api_config().api_url(
+            &sport,
+            &Endpoint::CompetitionSeasons {
+                competition_id: competition.id(),
+            },
+        );
 
         // Fetch the seasons for the selected competition
         let response = client
-            .get_json_data::<CompetitionSeasonsApiResponse<Season>>(api_url)
-            .await?;
+            .get_data::<CompetitionSeasonsApiResponse<Season>>(api_url, format)
+            .await;
+        flush_quota_usage(&self.api_config().quota_file);
+        let response = response?;
 
         // Filter out disabled seasons
-        let seasons = response
+        let seasons: Vec<Season> = response
             .seasons
             .into_iter()
             .filter(|season| season.is_enabled())
@@ -235,6 +284,7 @@ pub trait AppStateService<
             .collect();
 
         // Set fetched and filtered seasons
+        Span::current().record("seasons", seasons.len());
         self.set_seasons(seasons);
         Ok(())
     }
@@ -247,6 +297,10 @@ pub trait AppStateService<
     ///
     /// This method can return an error if there is a problem fetching the sport events, competitors,
     /// or players from the API or setting them in the application state.
+    #[tracing::instrument(
+        skip_all,
+        fields(season = field::Empty, competitors = field::Empty, players = field::Empty)
+    )]
     async fn on_season_select(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let sport = self
             .selected_sport()
@@ -254,21 +308,36 @@ pub trait AppStateService<
         let season = self
             .selected_season()
             .expect("No selected season to fetch sport events");
-
-        let api_url = get_api_url(&sport, format!("seasons/{}/schedules", season.id()));
+        Span::current().record("season", season.id());
+        self.check_budget(1)?;
+
+        let api_url = self.api_config().api_url(
+            &sport,
+            &Endpoint::SeasonSchedules {
+                season_id: season.id(),
+                start: None,
+                limit: None,
+            },
+        );
         let client = self.get_http_client();
+        let format = self.api_config().format;
 
         // Fetch the sport events for the selected season
         let response = client
-            .get_json_data::<SchedulesApiResponse<SportEvent>>(api_url)
-            .await
-            .expect("Failed to fetch sport events");
+            .get_data::<SchedulesApiResponse<SportEvent>>(api_url, format)
+            .await?;
 
         // Extract the sport events from the response
         let sport_events: HashSet<SportEvent> = response
             .schedules
             .into_iter()
-            .map(|schedule| schedule.sport_event)
+            .map(|schedule| {
+                let mut sport_event = schedule.sport_event;
+                if let Some(status) = schedule.sport_event_status {
+                    sport_event.set_status(status);
+                }
+                sport_event
+            })
             .collect();
 
         // Get the list of competitors from the sport_events
@@ -278,6 +347,7 @@ pub trait AppStateService<
             .collect();
 
         // Set sports events and competitors to the state
+        Span::current().record("competitors", competitors.len());
         self.set_sport_events(sport_events);
         self.set_competitors(competitors);
 
@@ -285,18 +355,102 @@ pub trait AppStateService<
         let players = self.fetch_players().await?;
 
         // Set players to the state
+        Span::current().record("players", players.len());
         self.set_players(players);
 
         Ok(())
     }
 
+    /// Returns a snapshot of the data loaded for the selected season, or `None` if the competition
+    /// or the season is not selected.
+    #[allow(clippy::type_complexity)]
+    fn season_snapshot(
+        &self,
+    ) -> Option<SeasonSnapshot<Competition, Season, SportEvent, Competitor, Player>> {
+        Some(SeasonSnapshot {
+            competition: self.selected_competition()?,
+            season: self.selected_season()?,
+            sport_events: self.sport_events().iter().cloned().collect(),
+            competitors: self.competitors().iter().cloned().collect(),
+            players: self.players().values().cloned().collect(),
+            fetched_at: Some(now_utc()),
+        })
+    }
+
+    /// Saves the snapshot of the selected season (see [`AppStateService::season_snapshot`]) to the
+    /// local cache directory of the config (see [`ApiConfig::cache_dir`]) to make it searchable
+    /// later. Does nothing if the season is not selected.
+    ///
+    /// The fetch itself never writes to the local cache, so the front ends call this method after
+    /// [`AppStateService::on_season_select`] when they want the season to be cached.
+    fn save_season_snapshot(&self) -> Result<(), Box<dyn Error>> {
+        if let Some(snapshot) = self.season_snapshot() {
+            save_season_snapshot(
+                &self.api_config().cache_dir,
+                &snapshot.season.id(),
+                &snapshot,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error if sending the planned number of API requests would go over the monthly
+    /// budget of the API key (see [`ApiConfig::monthly_budget`]), so that a fetch is refused before
+    /// it starts instead of failing halfway.
+    fn check_budget(&self, planned: usize) -> Result<(), String> {
+        check_quota_budget(self.api_config(), planned)
+    }
+
+    /// Returns the API requests [`AppStateService::on_season_select`] would send for the currently
+    /// selected season, without sending any of them: the schedules, then the statistics of every
+    /// competitor.
+    ///
+    /// The competitors are known before the schedules are fetched only if the season data is
+    /// already loaded (e.g. restored from the local cache with
+    /// [`AppStateService::restore_season_snapshot`]), otherwise the plan is not complete.
+    fn season_request_plan(&self) -> RequestPlan {
+        let sport = self
+            .selected_sport()
+            .expect("No selected sport to plan the requests");
+        let season = self
+            .selected_season()
+            .expect("No selected season to plan the requests");
+
+        let schedules = Endpoint::SeasonSchedules {
+            season_id: season.id(),
+            start: None,
+            limit: None,
+        };
+        let competitors = self
+            .competitors()
+            .iter()
+            .map(|competitor: &Competitor| competitor.id())
+            .sorted()
+            .map(|competitor_id| Endpoint::SeasonCompetitorStatistics {
+                season_id: season.id(),
+                competitor_id,
+            });
+
+        RequestPlan {
+            requests: [schedules]
+                .into_iter()
+                .chain(competitors)
+                .map(|endpoint| PlannedRequest {
+                    endpoint: endpoint.name(),
+                    url: scrub_api_key(&self.api_config().api_url(&sport, &endpoint)),
+                })
+                .collect(),
+            is_complete: !self.competitors().is_empty(),
+        }
+    }
+
     /// Returns a set of API URLs for fetching competitor statistics for the currently selected sport
     /// and season.
     ///
     /// This method iterates over the set of competitors in the application state, and generates an API
     /// URL for each competitor to fetch their statistics for the currently selected season. The API URLs
-    /// are constructed using the `get_api_url` function, which takes the selected sport and a path that
-    /// includes the season ID and competitor ID.
+    /// are constructed using the `ApiConfig::api_url` method, which takes the selected sport and an
+    /// `Endpoint::SeasonCompetitorStatistics` with the season ID and competitor ID.
     ///
     /// # Returns
     /// A `HashSet<String>` containing the API URLs for fetching competitor statistics.
@@ -312,13 +466,12 @@ pub trait AppStateService<
         competitors
             .iter()
             .map(|competitor: &Competitor| {
-                get_api_url(
+                self.api_config().api_url(
                     &sport,
-                    format!(
-                        "seasons/{}/competitors/{}/statistics",
-                        season.id(),
-                        competitor.id()
-                    ),
+                    &Endpoint::SeasonCompetitorStatistics {
+                        season_id: season.id(),
+                        competitor_id: competitor.id(),
+                    },
                 )
             })
             .collect()
@@ -335,7 +488,7 @@ pub trait AppStateService<
     /// # Arguments
     ///
     /// * `players` - A mutable reference to the players map, which will be updated with the data
-    ///     from the API response.
+    ///   from the API response.
     /// * `response` - The result of the API request, containing the competitor and player data.
     fn handle_competitor_statistics_response(
         &self,
@@ -374,7 +527,7 @@ pub trait AppStateService<
                 }
             }
             Err(err) => {
-                eprintln!("Error fetching competitor statistics: {}", err);
+                tracing::warn!(error = %err, "Failed to fetch the competitor statistics");
             }
         }
     }
@@ -385,34 +538,81 @@ pub trait AppStateService<
     /// and then updating the internal player map accordingly. If a player already exists in the map,
     /// their season statistics are updated. If a player is new, they are added to the map.
     ///
+    /// The progress of the requests (finished, failed, and retried) is reported to the progress
+    /// observer (see [`AppStateService::progress_observer`]). The fetch is refused if its requests
+    /// would go over the monthly budget (see [`AppStateService::check_budget`]).
+    ///
     /// # Returns
     /// A `Result` containing a `HashMap` of `Player` objects, keyed by their unique identifier.
+    #[tracing::instrument(skip_all, fields(failed = field::Empty, retries = field::Empty))]
     async fn fetch_players(
         &mut self,
     ) -> Result<HashMap<String, Player>, Box<dyn std::error::Error>> {
         let client = self.get_http_client();
+        let format = self.api_config().format;
 
         // Create a set of API URLs to fetch the statistics for each competitor
         let competitors_api_urls = self.competitors_api_urls();
+        self.check_budget(competitors_api_urls.len())?;
 
         let mut players = HashMap::new();
 
-        let competitors_api_calls = competitors_api_urls
-            .into_iter()
-            .map(|url| client.get_json_data::<CompetitorStatisticsApiResponse<Player>>(url));
-
-        // Fetch the statistics for each competitor concurrently using join_all
-        futures::future::join_all(competitors_api_calls)
-            .await
+        let tracker = Arc::new(ProgressTracker::new(
+            competitors_api_urls.len(),
+            self.progress_observer(),
+        ));
+        let progress = &tracker;
+
+        // The errors are kept as strings while the calls are in flight: `Box<dyn Error>` is not `Send`,
+        // and holding it inside `join_all` would make the whole future non-`Send`
+        let competitors_api_calls = competitors_api_urls.into_iter().map(|url| async move {
+            let response = client
+                .get_data::<CompetitorStatisticsApiResponse<Player>>(url, format)
+                .await
+                .map_err(|err| err.to_string());
+            progress.complete(response.is_ok());
+            response
+        });
+
+        // Fetch the statistics for each competitor concurrently using join_all. The HTTP client
+        // reports its retries to the tracker of the running task.
+        tracker.start();
+        let responses = Arc::clone(&tracker)
+            .track(futures::future::join_all(competitors_api_calls))
+            .await;
+        tracker.finish();
+        // The whole season is saved to the API usage at once, the requests are counted in memory
+        flush_quota_usage(&self.api_config().quota_file);
+
+        let progress = tracker.progress();
+        Span::current()
+            .record("failed", progress.failed)
+            .record("retries", progress.retries);
+
+        responses
             .into_iter()
             // Handle the response for each competitor
             .for_each(|response| {
-                self.handle_competitor_statistics_response(&mut players, response)
+                self.handle_competitor_statistics_response(
+                    &mut players,
+                    response.map_err(Into::into),
+                )
             });
 
         Ok(players)
     }
 
+    /// Gets the top players by the given statistics sorted in descending order.
+    ///
+    /// This method returns a vector of at most `limit` players, sorted in descending order by the
+    /// value of the statistics in the current season.
+    ///
+    /// # Returns
+    /// A vector of `Player` objects representing the top players.
+    fn get_top_players(&self, statistics: Players, limit: usize) -> Vec<Player> {
+        top_players(self.players().values(), statistics, limit)
+    }
+
     /// Gets the top goal scorers sorted in descending order.
     ///
     /// This method returns a vector of the top goal scorers, sorted in descending order by the number
@@ -422,12 +622,7 @@ pub trait AppStateService<
     /// # Returns
     /// A vector of `Player` objects representing the top goal scorers.
     fn get_top_goal_scorers(&self) -> Vec<Player> {
-        self.players()
-            .clone()
-            .into_values()
-            .sorted_by(|a, b| Ord::cmp(&b.season_goals(), &a.season_goals()))
-            .take(self.limit().unwrap_or(10))
-            .collect()
+        self.get_top_players(Players::TopGoalScorers, self.limit().unwrap_or(10))
     }
 
     /// Gets the top assistants sorted in descending order.
@@ -439,103 +634,46 @@ pub trait AppStateService<
     /// # Returns
     /// A vector of `Player` objects representing the top assistants.
     fn get_top_assistants(&self) -> Vec<Player> {
-        self.players()
-            .clone()
-            .into_values()
-            .sorted_by(|a, b| Ord::cmp(&b.season_assists(), &a.season_assists()))
-            .take(self.limit().unwrap_or(10))
-            .collect()
+        self.get_top_players(Players::TopAssistants, self.limit().unwrap_or(10))
     }
 
-    /// Runs the application, allowing the user to select a sport, competition, season, and player
-    /// statistics to view.
-    ///
-    /// This method is the main entry point for the application. It guides the user through the process
-    /// of selecting a sport, competition, season, and player statistics to view. If any of these are
-    /// not selected, the method prompts the user to make a selection.
-    ///
-    /// Once all selections are made, the method displays the top goal scorers or top assistants for the
-    /// selected season, based on the user's choice. The number of players displayed is determined by the
-    /// limit set by the user.
-    ///
-    /// The method runs in a loop, allowing the user to explore other competitions or seasons.
-    async fn run(&mut self) -> Result<(), Box<dyn Error>> {
-        loop {
-            // Reset the state
-            self.reset();
-
-            // If the sport is not selected let the user select it
-            if self.selected_sport().is_none() {
-                let sports = self.on_start().await?; // fetch sports and save them to the state
-                let options: Vec<&Sport> = sports.iter().collect(); // get the sports
-                let selected_sport = prompt_select("Select a sport:", options, 15)?;
-                self.set_selected_sport(selected_sport); // set the selected sport
-            }
-
-            // If the competition is not selected, let the user select it
-            if self.selected_competition().is_none() {
-                self.on_sport_select().await?; // fetch competitions and save them to the state
-                let competitions = self.competitions(); // get the competitions
-                let options: Vec<Competition> = competitions.into_iter().collect(); // convert to a select options
-                let selected_competition = prompt_select("Select a competition:", options, 15)?;
-                self.set_selected_competition(&selected_competition); // set the selected competition
-            }
-
-            // If the season is not selected, let the user select it
-            if self.selected_season().is_none() {
-                self.on_competition_select().await?; // fetch seasons and save them to the state
-                let seasons = self.seasons(); // get the seasons
-                let options: Vec<Season> = seasons.into_iter().collect(); // convert to a select options
-                let selected_season = prompt_select("Select a season:", options, 15)?;
-                self.set_selected_season(selected_season); // set the selected season
-            }
-
-            // If the player statistics are not selected, let the user select them
-            if self.selected_player_statistics().is_none() {
-                self.on_season_select().await?; // fetch players and save them to the state
-                let options: Vec<Players> = vec![Players::TopGoalScorers, Players::TopAssistants]; // player statistics options
-                let selected_player_statistics =
-                    prompt_select("What statistics do you want to see?", options, 15)?;
-                self.set_selected_player_statistics(selected_player_statistics);
-                // set the selected player statistics
-            }
-
-            // If the limit is not set, let the user set it
-            if self.limit().is_none() {
-                let limit = prompt_number("How many players do you want to see?")?; // user choice for the limit
-                self.set_limit(limit); // set the limit
-            }
+    /// Restores the season data from a snapshot (e.g. loaded from the local cache), making the
+    /// snapshot's competition and season the selected ones.
+    fn restore_season_snapshot(
+        &mut self,
+        snapshot: SeasonSnapshot<Competition, Season, SportEvent, Competitor, Player>,
+    ) {
+        self.set_selected_competition(&snapshot.competition);
+        self.set_selected_season(snapshot.season);
+        self.set_sport_events(snapshot.sport_events.into_iter().collect());
+        self.set_competitors(snapshot.competitors.into_iter().collect());
+        self.set_players(
+            snapshot
+                .players
+                .into_iter()
+                .map(|player| (player.id(), player))
+                .collect(),
+        );
+    }
 
-            // Print the players with their statistics depending on the selected player statistics
-            if let Some(option) = self.selected_player_statistics() {
-                match option {
-                    Players::TopGoalScorers => {
-                        let top_goal_scorers = self.get_top_goal_scorers();
-                        for (index, player) in top_goal_scorers.iter().enumerate() {
-                            println!(
-                                "{}. {} - {} goals",
-                                index + 1,
-                                player,
-                                player.season_goals()
-                            );
-                        }
-                    }
-                    Players::TopAssistants => {
-                        let top_assistants = self.get_top_assistants();
-                        for (index, player) in top_assistants.iter().enumerate() {
-                            println!(
-                                "{}. {} - {} assists",
-                                index + 1,
-                                player,
-                                player.season_assists()
-                            );
-                        }
-                    }
-                }
-            }
+    /// Searches the players of the selected season by name.
+    ///
+    /// The search ignores case and diacritics (so "Odegaard" matches "Ødegaard") and the
+    /// results are sorted by the match quality, the best match first.
+    fn search_players(&self, query: &str) -> Vec<Player> {
+        rank_matches(self.players().values(), query, |player| player.name())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
 
-            // Ask the users if they want to continue
-            prompt_boolean("Do you want to explore other sports, competitions, or seasons?");
-        }
+    /// Searches the competitors (teams) of the selected season by name.
+    ///
+    /// The search works the same way as [`AppStateService::search_players`].
+    fn search_teams(&self, query: &str) -> Vec<Competitor> {
+        rank_matches(self.competitors(), query, |team| team.name())
+            .into_iter()
+            .cloned()
+            .collect()
     }
 }
@@ -1,4 +1,4 @@
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -6,7 +6,7 @@ use std::{
 
 pub trait BaseInfoService
 where
-    Self: DeserializeOwned + Clone + PartialEq + Eq + Hash + Display + Debug,
+    Self: DeserializeOwned + Serialize + Clone + PartialEq + Eq + Hash + Display + Debug,
 {
     fn id(&self) -> String;
     fn name(&self) -> String;
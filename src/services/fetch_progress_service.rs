@@ -0,0 +1,19 @@
+use std::fmt::Debug;
+
+use crate::models::FetchProgress;
+
+/// An observer of the bulk fetches of the pipeline (currently the statistics of all the
+/// competitors of a season, see [`super::AppStateService::fetch_players`]), letting every front
+/// end show the progress its own way.
+///
+/// The methods are called from the fetching future, so they must return quickly.
+pub trait FetchProgressService: Debug + Send + Sync {
+    /// Called before the first request is sent.
+    fn on_start(&self, _progress: &FetchProgress) {}
+
+    /// Called after every finished request and every retry.
+    fn on_progress(&self, progress: &FetchProgress);
+
+    /// Called after all the requests are finished.
+    fn on_finish(&self, _progress: &FetchProgress) {}
+}
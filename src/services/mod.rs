@@ -4,10 +4,13 @@ mod base_info_service;
 mod competitors_service;
 mod construct_service;
 mod country_service;
+mod fetch_progress_service;
 mod player_season_statistics_service;
 mod player_service;
 mod player_statistics_service;
 mod season_service;
+mod sport_api_service;
+mod sport_event_status_service;
 
 pub use api_service::ApiService;
 pub use app_state_service::AppStateService;
@@ -15,7 +18,10 @@ pub use base_info_service::BaseInfoService;
 pub use competitors_service::CompetitorsService;
 pub use construct_service::ConstructService;
 pub use country_service::CountryService;
+pub use fetch_progress_service::FetchProgressService;
 pub use player_season_statistics_service::PlayerSeasonStatisticsService;
 pub use player_service::PlayerService;
 pub use player_statistics_service::PlayerStatisticsService;
 pub use season_service::SeasonService;
+pub use sport_api_service::SportApiService;
+pub use sport_event_status_service::SportEventStatusService;
@@ -2,4 +2,6 @@ use super::{BaseInfoService, PlayerStatisticsService};
 
 pub trait PlayerService<T: PlayerStatisticsService, Competitor: BaseInfoService> {
     fn set_team(&mut self, team: &Competitor);
+
+    fn team(&self) -> &Competitor;
 }
@@ -1,9 +1,9 @@
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use std::hash::Hash;
 
 pub trait PlayerStatisticsService
 where
-    Self: DeserializeOwned + Clone + PartialEq + Eq + Hash,
+    Self: DeserializeOwned + Serialize + Clone + PartialEq + Eq + Hash,
 {
     fn goals(&self) -> u8;
     fn assists(&self) -> u8;
@@ -0,0 +1,7 @@
+use crate::config::SportApi;
+
+pub trait SportApiService {
+    fn from_sport_api(sport_api: &SportApi) -> Self;
+
+    fn sport_api(&self) -> &SportApi;
+}
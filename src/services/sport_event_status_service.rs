@@ -0,0 +1,7 @@
+use crate::models::SportEventStatus;
+
+pub trait SportEventStatusService {
+    fn status(&self) -> Option<SportEventStatus>;
+
+    fn set_status(&mut self, status: SportEventStatus);
+}
@@ -0,0 +1,438 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use itertools::Itertools;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    widgets::{ListState, TableState},
+    DefaultTerminal,
+};
+
+use sportradar::{
+    enums::Players,
+    models::{AppState, Competition, CompetitionSeason, FetchProgress, Player},
+    services::{
+        ApiService, AppStateService, BaseInfoService, FetchProgressService,
+        PlayerSeasonStatisticsService, PlayerService, SportApiService,
+    },
+};
+
+use super::dashboard_view::draw_dashboard;
+
+/// The pane of the dashboard which receives the keyboard input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Competitions,
+    Seasons,
+    Leaderboard,
+}
+
+impl Focus {
+    fn next(self) -> Self {
+        match self {
+            Focus::Competitions => Focus::Seasons,
+            Focus::Seasons => Focus::Leaderboard,
+            Focus::Leaderboard => Focus::Competitions,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Focus::Competitions => Focus::Leaderboard,
+            Focus::Seasons => Focus::Competitions,
+            Focus::Leaderboard => Focus::Seasons,
+        }
+    }
+}
+
+/// The leaderboard column the players are sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Player,
+    Team,
+    Goals,
+    Assists,
+}
+
+impl SortColumn {
+    fn next(self) -> Self {
+        match self {
+            SortColumn::Player => SortColumn::Team,
+            SortColumn::Team => SortColumn::Goals,
+            SortColumn::Goals => SortColumn::Assists,
+            SortColumn::Assists => SortColumn::Player,
+        }
+    }
+
+    // The column showing the value of the metric
+    fn of_metric(metric: Players) -> Self {
+        match metric {
+            Players::TopGoalScorers => SortColumn::Goals,
+            Players::TopAssistants => SortColumn::Assists,
+        }
+    }
+
+    // Text columns are sorted alphabetically, numeric columns from the highest value
+    fn is_descending_by_default(self) -> bool {
+        matches!(self, SortColumn::Goals | SortColumn::Assists)
+    }
+}
+
+impl Display for SortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortColumn::Player => write!(f, "Player"),
+            SortColumn::Team => write!(f, "Team"),
+            SortColumn::Goals => write!(f, "Goals"),
+            SortColumn::Assists => write!(f, "Assists"),
+        }
+    }
+}
+
+/// Keeps the latest progress of the season fetch, which the dashboard shows in the status bar.
+#[derive(Debug, Default)]
+pub struct DashboardProgress {
+    progress: Mutex<FetchProgress>,
+}
+
+impl DashboardProgress {
+    fn progress(&self) -> FetchProgress {
+        *self.progress.lock().expect("Progress lock is poisoned")
+    }
+}
+
+impl FetchProgressService for DashboardProgress {
+    fn on_start(&self, progress: &FetchProgress) {
+        self.on_progress(progress);
+    }
+
+    fn on_progress(&self, progress: &FetchProgress) {
+        *self.progress.lock().expect("Progress lock is poisoned") = *progress;
+    }
+}
+
+/// The state of the full-screen dashboard. The data itself lives in the wrapped [`AppState`],
+/// the dashboard keeps only the UI state: the focused pane, the selections and the sorting.
+pub struct Dashboard<'a, HttpClient: ApiService> {
+    pub app: &'a mut AppState<HttpClient>,
+    pub focus: Focus,
+    pub competitions: Vec<Competition>,
+    pub competitions_state: ListState,
+    pub seasons_state: ListState,
+    pub leaderboard: Vec<Player>,
+    pub leaderboard_state: TableState,
+    pub metric: Players,
+    pub sort_column: SortColumn,
+    pub descending: bool,
+    pub status: String,
+    pub api_requests: usize,
+    pub progress: Arc<DashboardProgress>,
+}
+
+impl<'a, HttpClient: ApiService + Clone> Dashboard<'a, HttpClient> {
+    fn new(app: &'a mut AppState<HttpClient>) -> Self {
+        let progress = Arc::new(DashboardProgress::default());
+        app.set_progress_observer(progress.clone());
+
+        Self {
+            app,
+            focus: Focus::Competitions,
+            competitions: Vec::new(),
+            competitions_state: ListState::default(),
+            seasons_state: ListState::default(),
+            leaderboard: Vec::new(),
+            leaderboard_state: TableState::default(),
+            metric: Players::TopGoalScorers,
+            sort_column: SortColumn::Goals,
+            descending: true,
+            status: String::new(),
+            api_requests: 0,
+            progress,
+        }
+    }
+
+    /// Returns the player selected in the leaderboard (if any).
+    pub fn selected_player(&self) -> Option<&Player> {
+        self.leaderboard_state
+            .selected()
+            .and_then(|index| self.leaderboard.get(index))
+    }
+
+    // Show the status message immediately, before a long-running fetch starts
+    fn set_status(&mut self, terminal: &mut DefaultTerminal, status: impl Into<String>) {
+        self.status = status.into();
+        let _ = terminal.draw(|frame| draw_dashboard(frame, self));
+    }
+
+    async fn load_competitions(&mut self, terminal: &mut DefaultTerminal) {
+        self.set_status(terminal, "Fetching sports and competitions...");
+
+        let sports = match self.app.on_start().await {
+            Ok(sports) => sports,
+            Err(err) => return self.status = format!("Failed to fetch sports: {}", err),
+        };
+        // The dashboard shows the first configured sport (see the `--sport` option)
+        let first_sport = self.app.api_config().find_sport(None).ok().cloned();
+        let Some(sport) = sports
+            .into_iter()
+            .find(|sport| Some(sport.sport_api()) == first_sport.as_ref())
+        else {
+            return self.status = "No sports available".to_string();
+        };
+        self.app.set_selected_sport(&sport);
+
+        self.api_requests += 1;
+        match self.app.on_sport_select().await {
+            Ok(()) => {
+                self.competitions = self
+                    .app
+                    .competitions()
+                    .iter()
+                    .cloned()
+                    .sorted_by_key(|competition| competition.to_string())
+                    .collect();
+                self.competitions_state.select_first();
+                self.status = format!("{} competitions loaded", self.competitions.len());
+            }
+            Err(err) => self.status = format!("Failed to fetch competitions: {}", err),
+        }
+    }
+
+    async fn select_competition(&mut self, terminal: &mut DefaultTerminal) {
+        let Some(competition) = self
+            .competitions_state
+            .selected()
+            .and_then(|index| self.competitions.get(index))
+            .cloned()
+        else {
+            return;
+        };
+
+        if self.app.selected_competition().as_ref() != Some(&competition) {
+            self.app.reset_selected_competition();
+            self.app.set_selected_competition(&competition);
+            self.leaderboard.clear();
+        }
+
+        if self.app.seasons().is_empty() {
+            self.set_status(terminal, format!("Fetching seasons of {}...", competition));
+            self.api_requests += 1;
+            if let Err(err) = self.app.on_competition_select().await {
+                return self.status = format!("Failed to fetch seasons: {}", err);
+            }
+        }
+
+        self.seasons_state.select_first();
+        self.focus = Focus::Seasons;
+        self.status = format!("{} seasons loaded", self.app.seasons().len());
+    }
+
+    async fn select_season(&mut self, terminal: &mut DefaultTerminal) {
+        let Some(season): Option<CompetitionSeason> = self
+            .seasons_state
+            .selected()
+            .and_then(|index| self.app.seasons().get(index).cloned())
+        else {
+            return;
+        };
+
+        if self.app.selected_season().as_ref() != Some(&season) {
+            self.app.reset_selected_season();
+            self.app.set_selected_season(season.clone());
+        }
+
+        if self.app.players().is_empty() {
+            self.set_status(
+                terminal,
+                format!(
+                    "Fetching schedules and competitor statistics of {}...",
+                    season
+                ),
+            );
+            let result = self.fetch_season(terminal, &season).await;
+            // One request for the schedules, one per competitor, and the retries
+            let progress = self.progress.progress();
+            self.api_requests += 1 + progress.total + progress.retries;
+            if let Err(err) = result {
+                return self.status = format!("Failed to fetch the season: {}", err);
+            }
+        }
+
+        self.sort_leaderboard();
+        self.leaderboard_state.select_first();
+        self.focus = Focus::Leaderboard;
+        self.status = format!(
+            "{} players of {} teams loaded",
+            self.leaderboard.len(),
+            self.app.competitors().len()
+        );
+    }
+
+    // Fetch the season on a copy of the app state, so that the dashboard can be redrawn with the
+    // progress of the fetch meanwhile
+    async fn fetch_season(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        season: &CompetitionSeason,
+    ) -> Result<(), String> {
+        self.progress.on_progress(&FetchProgress::default());
+        let mut app = self.app.clone();
+        let result = {
+            let fetch = app.on_season_select();
+            tokio::pin!(fetch);
+            let mut redraw = tokio::time::interval(Duration::from_millis(100));
+
+            loop {
+                tokio::select! {
+                    result = &mut fetch => break result.map_err(|err| err.to_string()),
+                    _ = redraw.tick() => {
+                        let progress = self.progress.progress();
+                        // The schedules are still being fetched
+                        if progress.total == 0 {
+                            continue;
+                        }
+                        self.set_status(
+                            terminal,
+                            format!(
+                                "Fetching competitor statistics of {}: {}/{} ({} failed, {} retries)...",
+                                season,
+                                progress.completed,
+                                progress.total,
+                                progress.failed,
+                                progress.retries
+                            ),
+                        );
+                    }
+                }
+            }
+        };
+
+        if result.is_ok() {
+            if let Err(err) = app.save_season_snapshot() {
+                tracing::warn!(error = %err, "Failed to save the season to the local cache");
+            }
+        }
+        *self.app = app;
+        result
+    }
+
+    fn sort_leaderboard(&mut self) {
+        let mut players: Vec<Player> = self.app.players().values().cloned().collect();
+
+        players.sort_by(|a, b| {
+            let ordering = match self.sort_column {
+                SortColumn::Player => Ord::cmp(&a.name(), &b.name()),
+                SortColumn::Team => Ord::cmp(&a.team().name(), &b.team().name()),
+                SortColumn::Goals => Ord::cmp(&a.season_goals(), &b.season_goals()),
+                SortColumn::Assists => Ord::cmp(&a.season_assists(), &b.season_assists()),
+            };
+            let ordering = if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            ordering.then_with(|| Ord::cmp(&a.name(), &b.name()))
+        });
+
+        self.leaderboard = players;
+    }
+
+    fn set_sort_column(&mut self, sort_column: SortColumn) {
+        self.sort_column = sort_column;
+        self.descending = sort_column.is_descending_by_default();
+        self.sort_leaderboard();
+        self.leaderboard_state.select_first();
+    }
+
+    fn switch_metric(&mut self) {
+        self.metric = match self.metric {
+            Players::TopGoalScorers => Players::TopAssistants,
+            Players::TopAssistants => Players::TopGoalScorers,
+        };
+        self.set_sort_column(SortColumn::of_metric(self.metric));
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        let (len, selected) = match self.focus {
+            Focus::Competitions => (
+                self.competitions.len(),
+                self.competitions_state.selected_mut(),
+            ),
+            Focus::Seasons => (self.app.seasons().len(), self.seasons_state.selected_mut()),
+            Focus::Leaderboard => (
+                self.leaderboard.len(),
+                self.leaderboard_state.selected_mut(),
+            ),
+        };
+
+        if len == 0 {
+            return;
+        }
+        *selected = Some(match (*selected, down) {
+            (None, _) => 0,
+            (Some(index), true) => (index + 1).min(len - 1),
+            (Some(index), false) => index.saturating_sub(1),
+        });
+    }
+
+    async fn handle_enter(&mut self, terminal: &mut DefaultTerminal) {
+        match self.focus {
+            Focus::Competitions => self.select_competition(terminal).await,
+            Focus::Seasons => self.select_season(terminal).await,
+            Focus::Leaderboard => {}
+        }
+    }
+}
+
+/// Runs the full-screen terminal dashboard on top of the application state.
+///
+/// Keys: `Tab`/`Shift+Tab` switch the focused pane, `↑`/`↓` move the selection, `Enter` loads the
+/// selected competition or season, `m` switches the metric, `s` changes the sort column, `r`
+/// reverses the sort order, and `q`/`Esc` quits.
+pub async fn run_dashboard<HttpClient: ApiService + Clone>(
+    app: &mut AppState<HttpClient>,
+) -> Result<(), Box<dyn Error>> {
+    let mut terminal = ratatui::init();
+    let result = run_event_loop(&mut terminal, Dashboard::new(app)).await;
+    ratatui::restore();
+
+    result
+}
+
+async fn run_event_loop<HttpClient: ApiService + Clone>(
+    terminal: &mut DefaultTerminal,
+    mut dashboard: Dashboard<'_, HttpClient>,
+) -> Result<(), Box<dyn Error>> {
+    dashboard.load_competitions(terminal).await;
+
+    loop {
+        terminal.draw(|frame| draw_dashboard(frame, &mut dashboard))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Tab => dashboard.focus = dashboard.focus.next(),
+            KeyCode::BackTab => dashboard.focus = dashboard.focus.previous(),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.move_selection(true),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.move_selection(false),
+            KeyCode::Enter => dashboard.handle_enter(terminal).await,
+            KeyCode::Char('m') => dashboard.switch_metric(),
+            KeyCode::Char('s') => dashboard.set_sort_column(dashboard.sort_column.next()),
+            KeyCode::Char('r') => {
+                dashboard.descending = !dashboard.descending;
+                dashboard.sort_leaderboard();
+            }
+            _ => {}
+        }
+    }
+}
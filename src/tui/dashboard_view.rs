@@ -0,0 +1,212 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, List, Paragraph, Row, Table},
+    Frame,
+};
+
+use sportradar::{
+    enums::Players,
+    models::Player,
+    services::{
+        ApiService, AppStateService, BaseInfoService, PlayerSeasonStatisticsService, PlayerService,
+    },
+};
+
+use super::dashboard::{Dashboard, Focus, SortColumn};
+
+// Get a bordered block, highlighted when the pane is focused
+fn pane(title: impl Into<String>, focused: bool) -> Block<'static> {
+    let border_style = if focused {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    };
+
+    Block::default()
+        .title(title.into())
+        .borders(Borders::ALL)
+        .border_style(border_style)
+}
+
+fn highlight_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::Green)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draws the whole dashboard: competitions and seasons on the left, the leaderboard in the middle,
+/// the selected player on the right, and the status bar at the bottom.
+pub fn draw_dashboard<HttpClient: ApiService + Clone>(
+    frame: &mut Frame,
+    dashboard: &mut Dashboard<'_, HttpClient>,
+) {
+    let [main_area, help_area, status_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [navigation_area, leaderboard_area, detail_area] = Layout::horizontal([
+        Constraint::Percentage(25),
+        Constraint::Percentage(50),
+        Constraint::Percentage(25),
+    ])
+    .areas(main_area);
+    let [competitions_area, seasons_area] =
+        Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(navigation_area);
+
+    draw_competitions(frame, dashboard, competitions_area);
+    draw_seasons(frame, dashboard, seasons_area);
+    draw_leaderboard(frame, dashboard, leaderboard_area);
+    draw_player_detail(frame, dashboard, detail_area);
+
+    frame.render_widget(
+        Paragraph::new(
+            "Tab: switch pane | ↑↓: move | Enter: load | m: metric | s: sort column | r: reverse | q: quit",
+        )
+        .dark_gray(),
+        help_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!(
+            " {} | API requests this session: {}",
+            dashboard.status, dashboard.api_requests
+        ))
+        .black()
+        .on_green(),
+        status_area,
+    );
+}
+
+fn draw_competitions<HttpClient: ApiService + Clone>(
+    frame: &mut Frame,
+    dashboard: &mut Dashboard<'_, HttpClient>,
+    area: Rect,
+) {
+    let items = dashboard
+        .competitions
+        .iter()
+        .map(|competition| competition.to_string());
+    let list = List::new(items)
+        .block(pane("Competitions", dashboard.focus == Focus::Competitions))
+        .highlight_style(highlight_style());
+
+    frame.render_stateful_widget(list, area, &mut dashboard.competitions_state);
+}
+
+fn draw_seasons<HttpClient: ApiService + Clone>(
+    frame: &mut Frame,
+    dashboard: &mut Dashboard<'_, HttpClient>,
+    area: Rect,
+) {
+    let items = dashboard
+        .app
+        .seasons()
+        .iter()
+        .map(|season| season.to_string());
+    let list = List::new(items)
+        .block(pane("Seasons", dashboard.focus == Focus::Seasons))
+        .highlight_style(highlight_style());
+
+    frame.render_stateful_widget(list, area, &mut dashboard.seasons_state);
+}
+
+fn draw_leaderboard<HttpClient: ApiService + Clone>(
+    frame: &mut Frame,
+    dashboard: &mut Dashboard<'_, HttpClient>,
+    area: Rect,
+) {
+    let header_cell = |column: SortColumn| {
+        if column != dashboard.sort_column {
+            return column.to_string();
+        }
+        let arrow = if dashboard.descending { "▼" } else { "▲" };
+        format!("{} {}", column, arrow)
+    };
+    let header = Row::new(vec![
+        "#".to_string(),
+        header_cell(SortColumn::Player),
+        header_cell(SortColumn::Team),
+        header_cell(SortColumn::Goals),
+        header_cell(SortColumn::Assists),
+    ])
+    .bold()
+    .underlined();
+
+    let rows = dashboard
+        .leaderboard
+        .iter()
+        .enumerate()
+        .map(|(index, player)| {
+            Row::new(vec![
+                (index + 1).to_string(),
+                player.name(),
+                player.team().name(),
+                player.season_goals().to_string(),
+                player.season_assists().to_string(),
+            ])
+        });
+
+    let title = match dashboard.app.selected_season() {
+        Some(season) => format!("{} - {}", dashboard.metric, season),
+        None => dashboard.metric.to_string(),
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Fill(2),
+            Constraint::Fill(2),
+            Constraint::Length(8),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header)
+    .block(pane(title, dashboard.focus == Focus::Leaderboard))
+    .row_highlight_style(highlight_style());
+
+    frame.render_stateful_widget(table, area, &mut dashboard.leaderboard_state);
+}
+
+fn draw_player_detail<HttpClient: ApiService + Clone>(
+    frame: &mut Frame,
+    dashboard: &Dashboard<'_, HttpClient>,
+    area: Rect,
+) {
+    let lines = match dashboard.selected_player() {
+        Some(player) => {
+            let rank = |metric: Players| {
+                let value = |player: &Player| metric.season_value(player);
+                // Players with a higher value are ranked above the player
+                1 + dashboard
+                    .leaderboard
+                    .iter()
+                    .filter(|other| value(other) > value(player))
+                    .count()
+            };
+
+            vec![
+                Line::from(player.name()).bold(),
+                Line::from(player.team().name()),
+                Line::from(""),
+                Line::from(format!(
+                    "Goals:   {} (rank {})",
+                    player.season_goals(),
+                    rank(Players::TopGoalScorers)
+                )),
+                Line::from(format!(
+                    "Assists: {} (rank {})",
+                    player.season_assists(),
+                    rank(Players::TopAssistants)
+                )),
+            ]
+        }
+        None => vec![Line::from("No player selected").dark_gray()],
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(pane("Player", false)), area);
+}
@@ -0,0 +1,4 @@
+mod dashboard;
+mod dashboard_view;
+
+pub use dashboard::run_dashboard;
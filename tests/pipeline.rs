@@ -0,0 +1,126 @@
+//! Runs the whole fetch pipeline offline against the synthetic Premier League 2023/24 fixtures in
+//! `tests/fixtures/premier_league_2023_24`.
+//!
+//! The fixtures are not recorded API responses: they are written by hand in the shape of the
+//! responses the `RecordingClient` saves, with six teams, the results of their matches, and their
+//! top players of the season. The tests check the pipeline (the URLs, the parsing into the models,
+//! the leaderboards, and the budget), not the current schema of the API, so they do not catch the
+//! schema drift of the real responses. Record a season with `--record` to check against the real
+//! API.
+
+use std::{fs, path::PathBuf};
+
+use sportradar::{
+    client::ReplayClient,
+    models::{AppState, CompetitionSeason, Sport},
+    services::{
+        AppStateService, BaseInfoService, PlayerSeasonStatisticsService, SportApiService,
+        SportEventStatusService,
+    },
+    ApiConfig, SportApi,
+};
+
+const FIXTURES: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/premier_league_2023_24"
+);
+
+// Get the settings keeping the local cache and the API usage file of the test in its own
+// directory, so the tests neither read nor change the ones of the user or of each other
+fn isolated_config(test: &str) -> ApiConfig {
+    let dir = local_state_dir(test);
+    let _ = fs::remove_dir_all(&dir);
+    ApiConfig {
+        quota_file: dir.join("quota.json"),
+        cache_dir: dir,
+        ..ApiConfig::default()
+    }
+}
+
+fn local_state_dir(test: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("pipeline_local_state")
+        .join(test)
+}
+
+// Get the app state replaying the fixtures with the Premier League 2023/24 season selected
+async fn premier_league_2023_24(config: ApiConfig) -> AppState<ReplayClient> {
+    let mut app = AppState::new(ReplayClient::new(FIXTURES), config);
+    app.set_selected_sport(&Sport::from_sport_api(&SportApi::soccer()));
+
+    app.on_sport_select().await.unwrap();
+    let competition = app
+        .competitions()
+        .iter()
+        .find(|competition| competition.name() == "Premier League")
+        .cloned()
+        .expect("The Premier League is in the fixtures");
+    app.set_selected_competition(&competition);
+
+    app.on_competition_select().await.unwrap();
+    let season: CompetitionSeason = app
+        .seasons()
+        .iter()
+        .find(|season| season.id() == "sr:season:105353")
+        .cloned()
+        .expect("The 2023/24 season is in the fixtures");
+    app.set_selected_season(season);
+
+    app
+}
+
+#[tokio::test]
+async fn pipeline_finds_top_scorer() {
+    let mut app = premier_league_2023_24(isolated_config("top_scorer")).await;
+    // The disabled 2022/23 season is filtered out
+    assert_eq!(app.seasons().len(), 2);
+
+    app.on_season_select().await.unwrap();
+    assert_eq!(app.competitors().len(), 6);
+    assert_eq!(app.players().len(), 15);
+    // The results of the closed sport events are kept with them
+    let results = app
+        .sport_events()
+        .iter()
+        .filter_map(|sport_event| sport_event.status()?.final_score())
+        .count();
+    assert_eq!(results, 9);
+
+    let top_scorers = app.get_top_goal_scorers();
+    assert_eq!(top_scorers[0].name(), "Haaland, Erling");
+    assert_eq!(top_scorers[0].season_goals(), 27);
+    assert_eq!(top_scorers[1].name(), "Palmer, Cole");
+
+    let top_assistants = app.get_top_assistants();
+    assert_eq!(top_assistants[0].name(), "Watkins, Ollie");
+    assert_eq!(top_assistants[0].season_assists(), 13);
+}
+
+#[tokio::test]
+async fn pipeline_does_not_write_local_state() {
+    let config = isolated_config("local_state");
+    let mut app = premier_league_2023_24(config.clone()).await;
+    app.on_season_select().await.unwrap();
+
+    // The fetch neither caches the season nor counts the replayed responses as API usage
+    assert!(!config.cache_dir.exists());
+    assert!(!config.quota_file.exists());
+}
+
+#[tokio::test]
+async fn pipeline_refuses_fetch_over_budget() {
+    // The budget is checked against the usage file of the tests, which is empty
+    let config = ApiConfig {
+        monthly_budget: Some(5),
+        ..isolated_config("over_budget")
+    };
+    let mut app = premier_league_2023_24(config).await;
+
+    // The schedules fit in the budget, the statistics of the 6 competitors do not
+    let err = app.on_season_select().await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The fetch needs 6 API requests, but only 5 of the monthly budget of 5 are left"
+    );
+    assert!(app.players().is_empty());
+}